@@ -0,0 +1,217 @@
+/*
+Copyright 2023 Benjamin Richcreek
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+//! # Enum Unwrapper Core
+//!`enum_unwrapper_core` holds the traits and types used by the conversions that [`enum_unwrapper`](<https://docs.rs/enum_unwrapper>) generates, which a `proc_macro` crate cannot export itself.
+//!
+//!It also re-exports [`macro@unique_try_froms`] and [`derive@UniqueTryFrom`], so it is the only dependency needed to use them.
+//!
+//!Every [`enum`](<https://doc.rust-lang.org/1.58.1/std/keyword.enum.html>) annotated with either macro implements [`VariantOf`] for the type held by each of its unique variants, which allows generic code to be written over "any [`enum`](<https://doc.rust-lang.org/1.58.1/std/keyword.enum.html>) that unwraps to `T`".
+//!```
+//!use enum_unwrapper_core::{unique_try_froms, Unwrap};
+//!#[unique_try_froms()]
+//!enum NumberHolder {
+//!    U8(u8),
+//!    U16(u16),
+//!}
+//!fn small_or_zero<E: Unwrap<u8>>(value: E) -> u8 {
+//!    value.try_unwrap().unwrap_or(0)
+//!}
+//!assert_eq!(4, small_or_zero(NumberHolder::U8(4)));
+//!assert_eq!(0, small_or_zero(NumberHolder::U16(444)));
+//!```
+//!# No Std
+//!Neither this crate nor the code generated by the macros needs `std` or `alloc`, so they can be used in `#![no_std]` crates by disabling the default `std` feature.
+//!The feature only implements `std::error::Error` for the error types.
+//!```toml
+//![dependencies]
+//!enum_unwrapper_core = { version = "0.1.0", default-features = false }
+//!```
+#![cfg_attr(not(feature = "std"), no_std)]
+pub use enum_unwrapper::{unique_try_froms, UniqueTryFrom};
+use core::fmt;
+/// # Variant Of
+/// Implemented for each type `Self` that a variant of the [`enum`](<https://doc.rust-lang.org/1.58.1/std/keyword.enum.html>) `E` unwraps to.
+///
+/// Implementations are generated by [`macro@unique_try_froms`] and [`derive@UniqueTryFrom`], alongside the matching [`TryFrom`] implementations, for owned as well as borrowed [`enum`](<https://doc.rust-lang.org/1.58.1/std/keyword.enum.html>)s.
+pub trait VariantOf<E>: Sized {
+    /// The name of the variant holding `Self`
+    const VARIANT: &'static str;
+    /// Checks whether `value` is the variant holding `Self`
+    fn is_variant_of(value: &E) -> bool;
+    /// Unwraps `value` into `Self`, handing it back inside the error if it is another variant
+    fn try_from_variant(value: E) -> Result<Self, TryFromVariantError<E>>;
+    /// Unwraps `value` into `Self`, discarding it if it is another variant
+    ///
+    /// The generated implementations match on `value` directly, so no error is built just to be thrown away.
+    fn from_variant(value: E) -> Option<Self> {
+        Self::try_from_variant(value).ok()
+    }
+}
+/// # Unwrap
+/// Implemented by every [`enum`](<https://doc.rust-lang.org/1.58.1/std/keyword.enum.html>) `Self` that has a variant unwrapping to `T`.
+///
+/// This is the reverse of [`VariantOf`], and is implemented for every type it is, so bounds can be written as `E: Unwrap<T>`.
+pub trait Unwrap<T>: Sized {
+    /// Checks whether `self` is the variant holding a `T`
+    fn holds(&self) -> bool;
+    /// Unwraps `self` into a `T`, handing it back inside the error if it is another variant
+    fn try_unwrap(self) -> Result<T, TryFromVariantError<Self>>;
+    /// Unwraps `self` into a `T`, discarding it if it is another variant
+    fn try_into_inner(self) -> Option<T>;
+}
+impl<E, T: VariantOf<E>> Unwrap<T> for E {
+    fn holds(&self) -> bool {
+        T::is_variant_of(self)
+    }
+    fn try_unwrap(self) -> Result<T, TryFromVariantError<Self>> {
+        T::try_from_variant(self)
+    }
+    fn try_into_inner(self) -> Option<T> {
+        T::from_variant(self)
+    }
+}
+/// # Try From Variant Error
+/// The error returned when an [`enum`](<https://doc.rust-lang.org/1.58.1/std/keyword.enum.html>) does not hold the variant a conversion expected.
+///
+/// It records the name of the variant the conversion expected and the one it found, so it displays as "expected U8, found U16".
+/// The value that failed to convert is handed back by [`into_inner`](Self::into_inner), so another conversion can be attempted without cloning it.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct TryFromVariantError<E> {
+    expected: &'static str,
+    found: &'static str,
+    value: E,
+}
+impl<E> TryFromVariantError<E> {
+    /// Creates an error for `value`, which is the `found` variant rather than the `expected` one
+    pub fn new(expected: &'static str, found: &'static str, value: E) -> Self {
+        TryFromVariantError {
+            expected,
+            found,
+            value,
+        }
+    }
+    /// The name of the variant the conversion expected
+    pub fn expected(&self) -> &'static str {
+        self.expected
+    }
+    /// The name of the variant that was actually found
+    pub fn found(&self) -> &'static str {
+        self.found
+    }
+    /// Borrows the value that failed to convert
+    pub fn get_ref(&self) -> &E {
+        &self.value
+    }
+    /// Returns the value that failed to convert
+    pub fn into_inner(self) -> E {
+        self.value
+    }
+}
+impl<E> fmt::Debug for TryFromVariantError<E> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.debug_struct("TryFromVariantError")
+            .field("expected", &self.expected)
+            .field("found", &self.found)
+            .finish_non_exhaustive()
+    }
+}
+impl<E> fmt::Display for TryFromVariantError<E> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "expected {}, found {}", self.expected, self.found)
+    }
+}
+#[cfg(feature = "std")]
+impl<E> std::error::Error for TryFromVariantError<E> {}
+/// # Parse Kind Error
+/// The error returned when parsing a kind [`enum`](<https://doc.rust-lang.org/1.58.1/std/keyword.enum.html>), generated by the `kind` argument, from a string that is not the name of one of its variants.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseKindError {
+    kind: &'static str,
+}
+impl ParseKindError {
+    /// Creates an error for a failed attempt to parse the kind [`enum`](<https://doc.rust-lang.org/1.58.1/std/keyword.enum.html>) named `kind`
+    pub fn new(kind: &'static str) -> Self {
+        ParseKindError {
+            kind,
+        }
+    }
+    /// The name of the kind [`enum`](<https://doc.rust-lang.org/1.58.1/std/keyword.enum.html>) that failed to parse
+    pub fn kind(&self) -> &'static str {
+        self.kind
+    }
+}
+impl fmt::Display for ParseKindError {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "not the name of a variant of {}", self.kind)
+    }
+}
+#[cfg(feature = "std")]
+impl std::error::Error for ParseKindError {}
+/// Checks that the generated code compiles in the 2015 and 2018 editions, where [`TryFrom`] is not in the prelude
+///```edition2015
+///extern crate enum_unwrapper_core;
+///use std::convert::TryFrom;
+///use enum_unwrapper_core::unique_try_froms;
+///#[unique_try_froms(option, kind)]
+///enum NumberHolder {
+///    U8(u8),
+///    U16(u16),
+///}
+///fn main() {
+///    assert_eq!(4, u8::try_from(NumberHolder::U8(4)).unwrap());
+///    assert_eq!(Some(444), Option::<u16>::from(NumberHolder::U16(444)));
+///    assert_eq!(NumberHolderKind::U8, NumberHolder::U8(4).kind());
+///}
+///```
+///```edition2018
+///use std::convert::TryFrom;
+///use enum_unwrapper_core::unique_try_froms;
+///#[unique_try_froms(option, kind)]
+///enum NumberHolder {
+///    U8(u8),
+///    U16(u16),
+///}
+///fn main() {
+///    assert_eq!(4, u8::try_from(NumberHolder::U8(4)).unwrap());
+///    assert_eq!(Some(444), Option::<u16>::from(NumberHolder::U16(444)));
+///    assert_eq!(NumberHolderKind::U8, NumberHolder::U8(4).kind());
+///}
+///```
+#[cfg(doctest)]
+pub struct EditionTests;
+/// # Private
+/// Paths used by the generated code, so it keeps working when a crate shadows the prelude or predates it.
+///
+/// Nothing in here is part of the public API.
+#[doc(hidden)]
+pub mod __private {
+    pub use core::clone::Clone;
+    pub use core::cmp::{Eq, PartialEq};
+    pub use core::convert::{From, TryFrom};
+    pub use core::default::Default;
+    pub use core::fmt::{self, Debug};
+    pub use core::hash::Hash;
+    pub use core::marker::Copy;
+    pub use core::option::Option::{self, None, Some};
+    pub use core::result::Result::{self, Err, Ok};
+    pub use core::str::FromStr;
+    use crate::TryFromVariantError;
+    /// Panics on behalf of the generated `unwrap_as` method
+    #[track_caller]
+    pub fn unwrap_as_failed<E>(error: TryFromVariantError<E>) -> ! {
+        panic!("called `unwrap_as` on the wrong variant: {}", error)
+    }
+}
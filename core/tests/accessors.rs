@@ -0,0 +1,46 @@
+/*
+Copyright 2023 Benjamin Richcreek
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+//! Checks the `is_`, `as_`, `as_mut` and `into_` accessors generated for each variant
+#![allow(dead_code)]
+use enum_unwrapper_core::unique_try_froms;
+#[unique_try_froms(exclude_variants(Other))]
+#[derive(Debug, PartialEq)]
+enum Shape {
+    Circle(u32),
+    Rectangle { width: u32, height: u16 },
+    Other(u32),
+    Empty,
+}
+#[test]
+fn checks_the_variant() {
+    assert!(Shape::Circle(3).is_circle());
+    assert!(!Shape::Circle(3).is_rectangle());
+    assert!(Shape::Other(3).is_other());
+}
+#[test]
+fn borrows_the_fields() {
+    let mut shape = Shape::Rectangle { width: 4, height: 2 };
+    assert_eq!(Some((&4, &2)), shape.as_rectangle());
+    assert_eq!(None, shape.as_circle());
+    *shape.as_rectangle_mut().unwrap().1 += 1;
+    assert_eq!(Shape::Rectangle { width: 4, height: 3 }, shape);
+}
+#[test]
+fn hands_back_self_when_the_variant_differs() {
+    assert_eq!(Ok(7), Shape::Other(7).into_other());
+    assert_eq!(Err(Shape::Empty), Shape::Empty.into_circle());
+    assert_eq!(Err(Shape::Circle(3)), Shape::Circle(3).into_rectangle());
+}
@@ -0,0 +1,8 @@
+use enum_unwrapper_core::unique_try_froms;
+#[unique_try_froms(kind, unit_variants(marker))]
+enum Reply {
+    Kind,
+    TryFromError,
+    Code(u16),
+}
+fn main() {}
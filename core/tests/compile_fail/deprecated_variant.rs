@@ -0,0 +1,15 @@
+#![deny(deprecated)]
+use enum_unwrapper_core::unique_try_froms;
+#[unique_try_froms(kind, unit_variants(marker))]
+enum NumberHolder {
+    U8(u8),
+    #[deprecated(note = "use `U8` instead")]
+    Byte(i8),
+    #[deprecated]
+    Empty,
+}
+fn main() {
+    let _ = NumberHolder::U8(4).as_byte();
+    let _ = NumberHolderKind::Byte;
+    let _ = NumberHolderEmpty;
+}
@@ -0,0 +1,8 @@
+use enum_unwrapper_core::unique_try_froms;
+#[unique_try_froms()]
+enum Temperature {
+    Celsius(i16),
+    Fahrenheit(i16),
+    Kelvin(u16),
+}
+fn main() {}
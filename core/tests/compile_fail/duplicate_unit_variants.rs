@@ -0,0 +1,8 @@
+use enum_unwrapper_core::unique_try_froms;
+#[unique_try_froms(unit_variants(unit))]
+enum Reply {
+    Empty,
+    Unknown,
+    Code(u16),
+}
+fn main() {}
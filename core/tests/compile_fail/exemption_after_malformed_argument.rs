@@ -0,0 +1,7 @@
+use enum_unwrapper_core::unique_try_froms;
+#[unique_try_froms(bogus, exclude_variants(B))]
+enum Pair {
+    A(u8),
+    B(u8),
+}
+fn main() {}
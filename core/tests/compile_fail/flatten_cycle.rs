@@ -0,0 +1,14 @@
+use enum_unwrapper_core::unique_try_froms;
+#[unique_try_froms()]
+enum Inner {
+    Num(u8),
+    Text(String),
+}
+#[unique_try_froms()]
+enum Outer {
+    #[unwrap(flatten(u8))]
+    Recursive(Box<Outer>),
+    #[unwrap(flatten(Inner, Outer))]
+    Nested(Inner),
+}
+fn main() {}
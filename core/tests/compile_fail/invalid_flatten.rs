@@ -0,0 +1,16 @@
+use enum_unwrapper_core::unique_try_froms;
+#[unique_try_froms()]
+enum Inner {
+    Num(u8),
+    Text(String),
+}
+#[unique_try_froms()]
+enum Outer {
+    #[unwrap(flatten(u8, String, u8))]
+    Nested(Inner),
+    #[unwrap(flatten(u8))]
+    Pair(Inner, Inner),
+    #[unwrap(flatten)]
+    Unlisted(Box<Inner>),
+}
+fn main() {}
@@ -0,0 +1,7 @@
+use enum_unwrapper_core::unique_try_froms;
+#[unique_try_froms(bogus, exclude(u8, 16), unit_variants(nothing), kind)]
+enum NumberHolder {
+    U8(u8),
+    U16(u16),
+}
+fn main() {}
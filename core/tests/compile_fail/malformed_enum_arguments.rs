@@ -0,0 +1,8 @@
+use enum_unwrapper_core::UniqueTryFrom;
+#[derive(UniqueTryFrom)]
+#[unwrap(exclude(u8, 16), kind(yes))]
+enum NumberHolder {
+    U8(u8),
+    U16(u16),
+}
+fn main() {}
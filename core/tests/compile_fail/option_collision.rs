@@ -0,0 +1,8 @@
+use enum_unwrapper_core::unique_try_froms;
+#[unique_try_froms(option)]
+enum Reading {
+    Maybe(Option<u8>),
+    U8(u8),
+    U16(u16),
+}
+fn main() {}
@@ -0,0 +1,10 @@
+use enum_unwrapper_core::unique_try_froms;
+#[unique_try_froms()]
+enum Temperature {
+    #[try_from(primary)]
+    Celsius(i16),
+    #[try_from(primary)]
+    Fahrenheit(i16),
+    Kelvin(u16),
+}
+fn main() {}
@@ -0,0 +1,9 @@
+use enum_unwrapper_core::unique_try_froms;
+#[unique_try_froms()]
+enum Message<'a, T> {
+    Payload(T),
+    Borrowed(&'a T),
+    Boxed(Box<T>),
+    Code(u16),
+}
+fn main() {}
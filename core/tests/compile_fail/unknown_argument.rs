@@ -0,0 +1,7 @@
+use enum_unwrapper_core::unique_try_froms;
+#[unique_try_froms(bogus)]
+enum NumberHolder {
+    U8(u8),
+    U16(u16),
+}
+fn main() {}
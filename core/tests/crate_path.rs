@@ -0,0 +1,44 @@
+/*
+Copyright 2023 Benjamin Richcreek
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+//! Checks that the generated code can reach the runtime crate through the path given by `crate = "..."`
+#![allow(dead_code)]
+mod reexport {
+    pub use enum_unwrapper_core as runtime;
+}
+mod attribute {
+    use enum_unwrapper_core::unique_try_froms;
+    #[unique_try_froms(crate = "crate::reexport::runtime", kind, from, option)]
+    pub enum NumberHolder {
+        U8(u8),
+        U16(u16),
+    }
+}
+mod derive {
+    use enum_unwrapper_core::UniqueTryFrom;
+    #[derive(UniqueTryFrom)]
+    #[unwrap(crate = "crate::reexport::runtime", unit_variants(marker))]
+    pub enum Signal {
+        Level(u8),
+        Off,
+    }
+}
+use reexport::runtime::VariantOf;
+#[test]
+fn converts_through_the_reexport() {
+    assert_eq!(Some(4), u8::from_variant(attribute::NumberHolder::from(4u8)));
+    assert_eq!(attribute::NumberHolderKind::U16, attribute::NumberHolder::U16(4).kind());
+    assert!(derive::SignalOff::is_variant_of(&derive::Signal::Off));
+}
@@ -0,0 +1,76 @@
+/*
+Copyright 2023 Benjamin Richcreek
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+//! Checks conversions through `Box`, `Rc` and `Arc` payloads generated by the `deref` argument
+#![allow(dead_code)]
+use enum_unwrapper_core::{unique_try_froms, VariantOf};
+use std::fmt::Debug;
+use std::rc::Rc;
+use std::sync::Arc;
+#[unique_try_froms(deref, option, exclude(Box<Expression>))]
+#[derive(Debug)]
+enum Expression {
+    Number(Box<i64>),
+    Name(Box<str>),
+    Digits(Box<[u8]>),
+    Value(Box<dyn Debug>),
+    Shared(Rc<Vec<i64>>),
+    Synced(Arc<String>),
+    Nested(Box<Expression>),
+}
+#[unique_try_froms(deref)]
+#[derive(Debug, PartialEq)]
+enum List<T> {
+    Cons(Local<T>, Box<Self>),
+    Shared(u8, Rc<Self>),
+    Nil,
+}
+#[derive(Debug, PartialEq)]
+struct Local<T>(T);
+#[test]
+fn converts_recursive_enums() {
+    let list = List::Cons(Local(1), Box::new(List::Nil));
+    assert_eq!(Some((&Local(1), &Box::new(List::Nil))), <(&Local<u8>, &Box<List<u8>>)>::from_variant(&list));
+    assert_eq!(Ok((Local(1), Box::new(List::Nil))), <(Local<u8>, Box<List<u8>>)>::try_from(list).map_err(|_| ()));
+    assert!(<(&u8, &Rc<List<u8>>)>::try_from(&List::Shared(1, Rc::new(List::Nil))).is_ok());
+    assert_eq!("(Local<T>, Box<Self>)", List::Cons(Local(1), Box::new(List::Nil)).inner_type_name());
+}
+#[test]
+fn moves_out_of_boxes() {
+    assert_eq!(Ok(4), i64::try_from(Expression::Number(Box::new(4))).map_err(|_| ()));
+    assert_eq!(Some(4), Option::<i64>::from(Expression::Number(Box::new(4))));
+    assert!(Box::<i64>::try_from(Expression::Number(Box::new(4))).is_ok());
+}
+#[test]
+fn borrows_through_unsized_boxes() {
+    let mut name = Expression::Name(Box::from("x"));
+    assert_eq!(Some("x"), <&str>::from_variant(&name));
+    <&mut str>::try_from(&mut name).unwrap().make_ascii_uppercase();
+    assert_eq!(Some("X"), <&str>::from_variant(&name));
+    let digits = Expression::Digits(Box::from([1, 2].as_slice()));
+    assert_eq!(Some([1, 2].as_slice()), <&[u8]>::from_variant(&digits));
+    let value = Expression::Value(Box::new(4));
+    assert_eq!("4", format!("{:?}", <&dyn Debug>::try_from(&value).unwrap()));
+    assert!(Box::<str>::try_from(Expression::Name(Box::from("x"))).is_ok());
+}
+#[test]
+fn borrows_through_shared_pointers() {
+    let shared = Expression::Shared(Rc::new(vec![1, 2]));
+    assert_eq!(Some(&vec![1, 2]), <&Vec<i64>>::from_variant(&shared));
+    assert!(Rc::<Vec<i64>>::try_from(shared).is_ok());
+    let synced = Expression::Synced(Arc::new(String::from("x")));
+    assert_eq!(Some(&String::from("x")), <&String>::from_variant(&synced));
+    assert_eq!(None, <&String>::from_variant(&Expression::Number(Box::new(4))));
+}
@@ -0,0 +1,50 @@
+/*
+Copyright 2023 Benjamin Richcreek
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+//! Checks the `UniqueTryFrom` derive and its `unwrap(rename)` and `unwrap(skip)` helper attributes
+#![allow(dead_code)]
+use enum_unwrapper_core::UniqueTryFrom;
+#[derive(Debug, PartialEq, UniqueTryFrom)]
+enum Message {
+    #[unwrap(rename = "text")]
+    Utf8(String),
+    Code(u16),
+    #[unwrap(skip)]
+    Raw(u16),
+}
+#[cfg_attr(all(), derive(UniqueTryFrom))]
+enum Conditional {
+    Flag(bool),
+}
+#[test]
+fn renames_the_accessors() {
+    let mut message = Message::Utf8(String::from("hi"));
+    assert!(message.is_text());
+    assert_eq!(Some(&String::from("hi")), message.as_text());
+    message.as_text_mut().unwrap().push('!');
+    assert_eq!(Ok(String::from("hi!")), message.into_text());
+    assert_eq!("Utf8", Message::Utf8(String::new()).variant_name());
+}
+#[test]
+fn skips_the_conversion() {
+    assert_eq!(Ok(404), u16::try_from(Message::Code(404)));
+    let error = u16::try_from(Message::Raw(404)).unwrap_err();
+    assert_eq!(("Code", "Raw"), (error.expected(), error.found()));
+    assert_eq!(Some(&404), Message::Raw(404).as_raw());
+}
+#[test]
+fn composes_with_cfg_attr() {
+    assert_eq!(Some(true), bool::try_from(Conditional::Flag(true)).ok());
+}
@@ -0,0 +1,49 @@
+/*
+Copyright 2023 Benjamin Richcreek
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+//! Checks that every public item generated for a public enum is documented
+#![deny(missing_docs)]
+use enum_unwrapper_core::{unique_try_froms, UniqueTryFrom};
+/// Holds a number or nothing
+#[unique_try_froms(from, option, kind, deref, unit_variants(marker))]
+pub enum NumberHolder {
+    /// A small number
+    U8(u8),
+    /// A boxed number
+    Boxed(Box<u64>),
+    /// A number stored next to its label
+    Labelled {
+        /// The label of the number
+        label: &'static str,
+        /// The number itself
+        value: u16,
+    },
+    /// A nested number
+    #[unwrap(flatten(i8))]
+    Nested(Signed),
+    /// No number at all
+    Empty,
+}
+/// Holds a signed number
+#[derive(UniqueTryFrom)]
+#[unwrap(kind)]
+pub enum Signed {
+    /// A small signed number
+    I8(i8),
+}
+#[test]
+fn documented() {
+    assert_eq!(Some(4), NumberHolder::U8(4).try_into_inner::<u8>());
+}
@@ -0,0 +1,44 @@
+/*
+Copyright 2023 Benjamin Richcreek
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+//! Checks the error alias generated for the owned conversions
+#![allow(dead_code)]
+use enum_unwrapper_core::unique_try_froms;
+use core::fmt::Debug;
+#[unique_try_froms()]
+#[derive(Debug, PartialEq)]
+enum NumberHolder {
+    U8(u8),
+    U16(u16),
+}
+#[unique_try_froms(exclude(T))]
+#[derive(Debug, PartialEq)]
+enum Labelled<'a, T: Debug, const N: usize = 2> where T: PartialEq {
+    Value(T),
+    Label(&'a str),
+    Digits([u8; N]),
+}
+#[test]
+fn names_the_enum() {
+    let error: NumberHolderTryFromError = u8::try_from(NumberHolder::U16(444)).unwrap_err();
+    assert_eq!(NumberHolder::U16(444), error.into_inner());
+}
+#[test]
+fn carries_the_enum_generics() {
+    let error: LabelledTryFromError<'_, bool> = <&str>::try_from(Labelled::Value(true)).unwrap_err();
+    assert_eq!(Labelled::Value(true), error.into_inner());
+    let error: LabelledTryFromError<'_, bool, 3> = <[u8; 3]>::try_from(Labelled::Label("digits")).unwrap_err();
+    assert_eq!("Label", error.found());
+}
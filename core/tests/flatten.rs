@@ -0,0 +1,73 @@
+/*
+Copyright 2023 Benjamin Richcreek
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+//! Checks conversions through nested enums marked with `unwrap(flatten(...))`
+#![allow(dead_code)]
+use enum_unwrapper_core::{unique_try_froms, UniqueTryFrom, VariantOf};
+#[unique_try_froms()]
+#[derive(Debug, PartialEq)]
+enum Number {
+    Small(u8),
+    Large(u64),
+}
+#[derive(Debug, PartialEq, UniqueTryFrom)]
+enum Value {
+    #[unwrap(flatten(u8, u64))]
+    Number(Number),
+    Text(String),
+}
+#[unique_try_froms()]
+#[derive(Debug, PartialEq)]
+enum Field {
+    #[unwrap(flatten(u8, u64))]
+    Number { value: Number },
+    Name(&'static str),
+}
+#[unique_try_froms()]
+#[derive(Debug, PartialEq)]
+enum Document {
+    #[unwrap(flatten(u8, u64, String))]
+    Value(Value),
+    Flag(bool),
+}
+#[test]
+fn converts_through_the_nested_enum() {
+    assert_eq!(Ok(4), u8::try_from(Value::Number(Number::Small(4))));
+    assert_eq!(Ok(Number::Large(4)), Number::try_from(Value::Number(Number::Large(4))));
+    assert!(u64::is_variant_of(&Value::Number(Number::Large(4))));
+    assert!(!u64::is_variant_of(&Value::Number(Number::Small(4))));
+}
+#[test]
+fn converts_through_struct_like_variants() {
+    let field = Field::Number { value: Number::Large(4) };
+    assert!(u64::is_variant_of(&field));
+    assert!(!u8::is_variant_of(&field));
+    assert_eq!(Some(4), u64::from_variant(field));
+    let error = u8::try_from(Field::Number { value: Number::Large(4) }).unwrap_err();
+    assert_eq!(Field::Number { value: Number::Large(4) }, error.into_inner());
+}
+#[test]
+fn converts_through_several_levels() {
+    assert_eq!(Some(4), u64::from_variant(Document::Value(Value::Number(Number::Large(4)))));
+    assert_eq!(Ok(String::from("four")), String::try_from(Document::Value(Value::Text(String::from("four")))));
+}
+#[test]
+fn hands_back_the_outer_enum() {
+    let error = u8::try_from(Value::Number(Number::Large(4))).unwrap_err();
+    assert_eq!(("Small", "Large"), (error.expected(), error.found()));
+    assert_eq!(Value::Number(Number::Large(4)), error.into_inner());
+    let error = u8::try_from(Document::Flag(true)).unwrap_err();
+    assert_eq!(("Value", "Flag"), (error.expected(), error.found()));
+}
@@ -0,0 +1,92 @@
+/*
+Copyright 2023 Benjamin Richcreek
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+//! Checks that the generated code only refers to paths that the annotated crate cannot shadow
+#![allow(dead_code, unused_macros)]
+mod shadowed {
+    use enum_unwrapper_core::{unique_try_froms, UniqueTryFrom};
+    pub type Result<T> = ::core::result::Result<T, ()>;
+    pub type Option = ();
+    pub struct Ok;
+    pub struct Err;
+    pub struct Some;
+    pub struct None;
+    pub trait TryFrom {}
+    pub trait From {}
+    pub trait FromStr {}
+    pub mod std {}
+    pub mod core {}
+    macro_rules! panic {
+        ($($tokens:tt)*) => {
+            compile_error!("the prelude `panic` is shadowed")
+        };
+    }
+    macro_rules! stringify {
+        ($($tokens:tt)*) => {
+            compile_error!("the prelude `stringify` is shadowed")
+        };
+    }
+    #[unique_try_froms(from, option, kind, deref, unit_variants(marker))]
+    pub enum Message {
+        Byte(u8),
+        Pair(u16, u32),
+        Named { field_0: i8, other: i16, value: i32 },
+        Boxed(Box<i64>),
+        #[unwrap(flatten(char))]
+        Nested(Letter),
+        Empty,
+    }
+    #[derive(UniqueTryFrom)]
+    pub enum Letter {
+        Char(char),
+    }
+}
+mod without_prelude {
+    #![no_implicit_prelude]
+    use ::enum_unwrapper_core::unique_try_froms;
+    #[unique_try_froms(option, kind)]
+    pub enum NumberHolder<'a> {
+        U8(u8),
+        Text(&'a str),
+    }
+}
+mod generated_names {
+    use enum_unwrapper_core::unique_try_froms;
+    #[unique_try_froms()]
+    pub enum Wrapper<'__unique_try_froms, __UniqueTryFroms> {
+        Borrowed(&'__unique_try_froms str),
+        Local(Local<__UniqueTryFroms>),
+    }
+    pub struct Local<T>(pub T);
+}
+use shadowed::{Message, MessageEmpty};
+use without_prelude::NumberHolder;
+use generated_names::Wrapper;
+#[test]
+fn shadowed_prelude() {
+    assert_eq!(Some(4), Message::Byte(4).try_into_inner::<u8>());
+    assert_eq!(Some((-1, 2, 3)), <(i8, i16, i32)>::try_from(Message::Named { field_0: -1, other: 2, value: 3 }).ok());
+    assert_eq!(Some(MessageEmpty), Message::Empty.try_into_inner());
+}
+#[test]
+fn no_implicit_prelude() {
+    assert_eq!(Some("four"), Option::<&str>::from(NumberHolder::Text("four")));
+}
+#[test]
+fn generated_names() {
+    assert_eq!(Some(&"four"), Wrapper::<u8>::Borrowed("four").as_borrowed());
+    assert!(Wrapper::<u8>::Borrowed("four").is::<&str>());
+    assert_eq!(Some(&"four"), <&&str>::try_from(&Wrapper::<u8>::Borrowed("four")).ok());
+}
@@ -0,0 +1,43 @@
+/*
+Copyright 2023 Benjamin Richcreek
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+//! Checks `inner_type_name` and `INNER_TYPE_NAMES`
+#![allow(dead_code)]
+use enum_unwrapper_core::unique_try_froms;
+#[unique_try_froms(exclude(T))]
+enum Record<'a, T> {
+    Id(u64),
+    Tags(Vec<&'a str>),
+    Point { x: i32, y: i32 },
+    Pair(std::string::String, [u8; 4]),
+    Value(T),
+    Missing,
+}
+#[test]
+fn names_every_inner_type() {
+    assert_eq!(&[
+        ("Id", "u64"),
+        ("Tags", "Vec<&'a str>"),
+        ("Point", "(i32, i32)"),
+        ("Pair", "(std::string::String, [u8; 4])"),
+        ("Value", "T"),
+        ("Missing", "()"),
+    ], Record::<'_, bool>::INNER_TYPE_NAMES);
+    assert_eq!("u64", Record::<'_, bool>::Id(3).inner_type_name());
+    let point: Record<bool> = Record::Point { x: 1, y: 2 };
+    assert_eq!("(i32, i32)", point.inner_type_name());
+    assert_eq!("T", Record::Value(true).inner_type_name());
+    assert_eq!("()", Record::<'_, bool>::Missing.inner_type_name());
+}
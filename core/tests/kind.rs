@@ -0,0 +1,39 @@
+/*
+Copyright 2023 Benjamin Richcreek
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+//! Checks the kind enum generated by the `kind` argument
+#![allow(dead_code)]
+use enum_unwrapper_core::{unique_try_froms, ParseKindError};
+#[unique_try_froms(kind)]
+enum Shape<'a> {
+    Circle(u32),
+    Polygon { sides: &'a [u32] },
+    Empty,
+}
+#[test]
+fn mirrors_the_variants() {
+    assert_eq!(ShapeKind::Circle, Shape::Circle(3).kind());
+    assert_eq!(ShapeKind::Polygon, Shape::Polygon { sides: &[1, 2, 3] }.kind());
+    assert_eq!(ShapeKind::Empty, Shape::Empty.kind());
+    assert_eq!(&[ShapeKind::Circle, ShapeKind::Polygon, ShapeKind::Empty], ShapeKind::ALL);
+}
+#[test]
+fn round_trips_through_names() {
+    for kind in ShapeKind::ALL {
+        assert_eq!(Ok(*kind), kind.name().parse());
+        assert_eq!(kind.name(), kind.to_string());
+    }
+    assert_eq!(Err(ParseKindError::new("ShapeKind")), "Square".parse::<ShapeKind>());
+}
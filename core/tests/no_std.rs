@@ -0,0 +1,67 @@
+/*
+Copyright 2023 Benjamin Richcreek
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+//! Checks that the generated code only needs `core`, so it can be used in `#![no_std]` crates without `alloc`
+//!
+//! Running the tests with `--no-default-features` checks the runtime crate itself without `std` as well.
+#![no_std]
+use core::fmt::{self, Write};
+use enum_unwrapper_core::unique_try_froms;
+#[unique_try_froms(from, option, kind, unit_variants(marker))]
+#[derive(Debug, PartialEq)]
+enum Packet<'a> {
+    Ack(u8),
+    Payload(&'a [u8]),
+    Reading { sensor: u16, value: i32 },
+    #[unwrap(flatten(u32))]
+    Nested(Register),
+    Reset,
+}
+#[unique_try_froms()]
+#[derive(Debug, PartialEq)]
+enum Register {
+    Value(u32),
+}
+/// Collects formatted text without allocating
+struct Buffer {
+    bytes: [u8; 64],
+    length: usize,
+}
+impl Write for Buffer {
+    fn write_str(&mut self, text: &str) -> fmt::Result {
+        let end = self.length + text.len();
+        self.bytes.get_mut(self.length..end).ok_or(fmt::Error)?.copy_from_slice(text.as_bytes());
+        self.length = end;
+        Ok(())
+    }
+}
+fn display(value: impl fmt::Display) -> Buffer {
+    let mut buffer = Buffer { bytes: [0; 64], length: 0 };
+    write!(buffer, "{}", value).unwrap();
+    buffer
+}
+#[test]
+fn converts_without_std() {
+    assert_eq!(Ok(4), u8::try_from(Packet::Ack(4)));
+    assert_eq!(Some(9), Option::<u32>::from(Packet::Nested(Register::Value(9))));
+    assert_eq!(PacketKind::Reset, Packet::from(PacketReset).kind());
+}
+#[test]
+fn formats_errors_without_std() {
+    let error = display(u8::try_from(Packet::Reset).unwrap_err());
+    assert_eq!(b"expected Ack, found Reset", &error.bytes[..error.length]);
+    let error = display("Restart".parse::<PacketKind>().unwrap_err());
+    assert_eq!(b"not the name of a variant of PacketKind", &error.bytes[..error.length]);
+}
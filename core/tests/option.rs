@@ -0,0 +1,63 @@
+/*
+Copyright 2023 Benjamin Richcreek
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+//! Checks the conversions into `Option` generated by the `option` argument
+#![allow(dead_code)]
+use enum_unwrapper_core::{unique_try_froms, UniqueTryFrom};
+#[derive(Debug, PartialEq, UniqueTryFrom)]
+#[unwrap(option)]
+enum Inner {
+    Num(u8),
+    Text(String),
+}
+#[unique_try_froms(option, unit_variants(marker), exclude_variants(Ignored))]
+#[derive(Debug, PartialEq)]
+enum Outer {
+    Code(u16),
+    Pair(u8, bool),
+    #[unwrap(flatten(u8, String))]
+    Nested(Inner),
+    Ignored(i8),
+    Empty,
+}
+#[unique_try_froms(option, exclude_variants(Maybe))]
+enum Reading {
+    Maybe(Option<u8>),
+    U8(u8),
+}
+#[test]
+fn converts_the_matching_variant() {
+    assert_eq!(Some(404), Option::<u16>::from(Outer::Code(404)));
+    assert_eq!(Some((1, true)), Option::<(u8, bool)>::from(Outer::Pair(1, true)));
+    assert_eq!(Some(OuterEmpty), Option::from(Outer::Empty));
+    assert_eq!(Some(String::from("x")), Option::from(Inner::Text(String::from("x"))));
+}
+#[test]
+fn discards_other_variants() {
+    assert_eq!(None, Option::<u16>::from(Outer::Empty));
+    let converted: Option<u8> = Outer::Code(404).into();
+    assert_eq!(None, converted);
+}
+#[test]
+fn exempts_optional_variants() {
+    assert_eq!(Some(4), Option::<u8>::from(Reading::U8(4)));
+    assert_eq!(None, Option::<u8>::from(Reading::Maybe(Some(4))));
+}
+#[test]
+fn converts_through_flattened_variants() {
+    assert_eq!(Some(4), Option::<u8>::from(Outer::Nested(Inner::Num(4))));
+    assert_eq!(None, Option::<String>::from(Outer::Nested(Inner::Num(4))));
+    assert_eq!(Some(Inner::Num(4)), Option::from(Outer::Nested(Inner::Num(4))));
+}
@@ -0,0 +1,58 @@
+/*
+Copyright 2023 Benjamin Richcreek
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+//! Checks the `VariantOf` and `Unwrap` implementations and the error type shared through the runtime crate
+#![allow(dead_code)]
+use enum_unwrapper_core::{unique_try_froms, TryFromVariantError, Unwrap, VariantOf};
+#[unique_try_froms()]
+#[derive(Debug, PartialEq)]
+enum NumberHolder {
+    U8(u8),
+    U16(u16),
+}
+fn total<E: Unwrap<u8> + Unwrap<u16>>(values: Vec<E>) -> u32 {
+    values.into_iter().map(|value| match Unwrap::<u8>::try_unwrap(value) {
+        Ok(small) => u32::from(small),
+        Err(error) => Unwrap::<u16>::try_into_inner(error.into_inner()).map_or(0, u32::from),
+    }).sum()
+}
+#[test]
+fn unwraps_generically() {
+    assert_eq!(448, total(vec![NumberHolder::U8(4), NumberHolder::U16(444)]));
+    assert!(Unwrap::<u8>::holds(&NumberHolder::U8(4)));
+    assert!(!u16::is_variant_of(&NumberHolder::U8(4)));
+}
+#[test]
+fn unwraps_borrowed_enums() {
+    let mut number = NumberHolder::U16(444);
+    assert_eq!(Some(&444), <&u16>::from_variant(&number));
+    *<&mut u16>::try_from_variant(&mut number).unwrap() += 1;
+    assert_eq!(NumberHolder::U16(445), number);
+}
+#[test]
+fn describes_the_failure() {
+    let error: TryFromVariantError<NumberHolder> = u8::try_from_variant(NumberHolder::U16(444)).unwrap_err();
+    assert_eq!(("U8", "U16"), (error.expected(), error.found()));
+    assert_eq!(&NumberHolder::U16(444), error.get_ref());
+    assert_eq!("expected U8, found U16", error.to_string());
+    assert_eq!("TryFromVariantError { expected: \"U8\", found: \"U16\", .. }", format!("{:?}", error));
+}
+#[cfg(feature = "std")]
+#[test]
+fn is_an_error() {
+    let error = u8::try_from_variant(NumberHolder::U16(444)).unwrap_err();
+    let error: &dyn std::error::Error = &error;
+    assert!(error.source().is_none());
+}
@@ -0,0 +1,45 @@
+/*
+Copyright 2023 Benjamin Richcreek
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+//! Checks the `is`, `try_as`, `try_into_inner` and `unwrap_as` methods keyed on the inner type
+#![allow(dead_code)]
+use enum_unwrapper_core::unique_try_froms;
+#[unique_try_froms()]
+#[derive(Debug, PartialEq)]
+enum Setting {
+    Enabled(bool),
+    Level(u8),
+    Name(String),
+}
+#[test]
+fn checks_the_inner_type() {
+    assert!(Setting::Level(3).is::<u8>());
+    assert!(!Setting::Level(3).is::<bool>());
+}
+#[test]
+fn converts_into_the_inner_type() {
+    assert_eq!(Ok(3), Setting::Level(3).try_as::<u8>());
+    let error = Setting::Level(3).try_as::<String>().unwrap_err();
+    assert_eq!(("Name", "Level"), (error.expected(), error.found()));
+    assert_eq!(Setting::Level(3), error.into_inner());
+    assert_eq!(Some(true), Setting::Enabled(true).try_into_inner());
+    assert_eq!(None::<bool>, Setting::Level(3).try_into_inner());
+    assert_eq!(String::from("fast"), Setting::Name(String::from("fast")).unwrap_as::<String>());
+}
+#[test]
+#[should_panic(expected = "called `unwrap_as` on the wrong variant: expected Enabled, found Level")]
+fn panics_on_the_wrong_variant() {
+    Setting::Level(3).unwrap_as::<bool>();
+}
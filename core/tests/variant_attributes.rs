@@ -0,0 +1,46 @@
+/*
+Copyright 2023 Benjamin Richcreek
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+//! Checks that `#[cfg(...)]` and `#[deprecated]` on variants carry over to what is generated for them
+#![deny(deprecated)]
+use enum_unwrapper_core::unique_try_froms;
+#[unique_try_froms(from, option, kind, unit_variants(marker))]
+#[derive(Debug, PartialEq)]
+enum NumberHolder {
+    U8(u8),
+    #[cfg(any())]
+    U128(u128),
+    #[cfg_attr(all(), cfg(any()))]
+    Missing(Vec<u8>),
+    #[cfg_attr(all(), deprecated)]
+    Byte(i8),
+    #[deprecated(note = "use `U8` instead")]
+    Empty,
+}
+#[test]
+fn compiled_out_variants() {
+    assert_eq!(&["U8", "Byte", "Empty"], NumberHolder::VARIANT_NAMES);
+    assert_eq!(3, NumberHolderKind::ALL.len());
+    assert_eq!(4, u8::try_from(NumberHolder::U8(4)).unwrap());
+    assert_eq!(Some(4), Option::<u8>::from(NumberHolder::from(4u8)));
+}
+#[test]
+#[allow(deprecated)]
+fn deprecated_variants() {
+    assert_eq!(Some(&-4), NumberHolder::Byte(-4).as_byte());
+    assert!(NumberHolder::Empty.is_empty());
+    assert_eq!(NumberHolderEmpty, NumberHolderEmpty::try_from(NumberHolder::Empty).unwrap());
+    assert_eq!(Ok(NumberHolderKind::Byte), "Byte".parse());
+}
@@ -0,0 +1,33 @@
+/*
+Copyright 2023 Benjamin Richcreek
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+//! Checks `variant_name` and `VARIANT_NAMES`
+#![allow(dead_code)]
+use enum_unwrapper_core::unique_try_froms;
+#[unique_try_froms(exclude_variants(Other))]
+enum Shape {
+    Circle(u32),
+    Rectangle { width: u32, height: u32 },
+    Other(u32),
+    Empty,
+}
+#[test]
+fn names_every_variant() {
+    assert_eq!(&["Circle", "Rectangle", "Other", "Empty"], Shape::VARIANT_NAMES);
+    assert_eq!("Circle", Shape::Circle(3).variant_name());
+    assert_eq!("Rectangle", Shape::Rectangle { width: 4, height: 2 }.variant_name());
+    assert_eq!("Other", Shape::Other(3).variant_name());
+    assert_eq!("Empty", Shape::Empty.variant_name());
+}
@@ -18,14 +18,20 @@ limitations under the License.
 //!
 //!`enum_unrapper` does this by allowing the user to add a procedural macro attribute, [`macro@unique_try_froms`] to [`enum`](<https://doc.rust-lang.org/1.58.1/std/keyword.enum.html>) definitions.
 //!
+//!The same implementations can be generated with [`derive@UniqueTryFrom`], which composes better with other derives.
+//!
+//!The generated code relies on the companion `enum_unwrapper_core` crate, which re-exports both macros and should be depended upon instead of this crate.
+//!Since version 0.2.0 the generated code no longer compiles without it, see [Runtime Crate](macro@unique_try_froms#runtime-crate) for crates that depend on it under another path.
+//!
 //!For more information and examples, check the attribute's [documentation](macro@unique_try_froms).
-use syn;
-use quote::quote;
+use syn::parse::Parse;
+use quote::{format_ident, quote, quote_spanned};
 use proc_macro::TokenStream;
 /// # Unique TryFroms
 /// Add this attribute to [`enum`](<https://doc.rust-lang.org/1.58.1/std/keyword.enum.html>) definitions, and it will implement [`TryFrom`] for each standalone type contained in a variant of that [`enum`](<https://doc.rust-lang.org/1.58.1/std/keyword.enum.html>)/
 /// # Example
-/// ```no_run
+/// ```
+///# use enum_unwrapper_core::unique_try_froms;
 /// #[unique_try_froms()]
 /// enum NumberHolder {
 ///    U8(u8),
@@ -38,45 +44,1406 @@ use proc_macro::TokenStream;
 ///    assert_eq!(444,u16::try_from(big_number).unwrap());
 ///}
 ///```
-///note: this example is not automatically tested due to restrictions on `proc_macro` crates
-/// # Panics
-/// The macro panics when attached to anything other than an [`enum`](<https://doc.rust-lang.org/1.58.1/std/keyword.enum.html>) definition.
+/// # Borrowing
+/// Every conversion is also implemented for shared and mutable references, so a borrowed [`enum`](<https://doc.rust-lang.org/1.58.1/std/keyword.enum.html>) can be inspected or modified without moving or cloning its contents.
+/// ```
+///# use enum_unwrapper_core::unique_try_froms;
+///# #[unique_try_froms()]
+///# enum NumberHolder {
+///#    U8(u8),
+///#    U16(u16),
+///# }
+///let mut number = NumberHolder::U16(444);
+///assert!(<&u8>::try_from(&number).is_err());
+///*<&mut u16>::try_from(&mut number).unwrap() += 1;
+///assert_eq!(&445, <&u16>::try_from(&number).unwrap());
+///```
+/// # Kind Enum
+/// The `kind` argument generates a fieldless [`enum`](<https://doc.rust-lang.org/1.58.1/std/keyword.enum.html>) with the same variants, named after the original, along with a `kind(&self)` method returning the variant of a value.
+/// The kind [`enum`](<https://doc.rust-lang.org/1.58.1/std/keyword.enum.html>) is [`Copy`], implements [`Display`](std::fmt::Display) and [`FromStr`](std::str::FromStr) using the variant names, and lists every variant in its `ALL` constant.
+/// ```
+///# use enum_unwrapper_core::unique_try_froms;
+/// #[unique_try_froms(kind)]
+/// enum NumberHolder {
+///    U8(u8),
+///    U16(u16),
+///}
+///fn main() {
+///    assert_eq!(NumberHolderKind::U16, NumberHolder::U16(444).kind());
+///    assert_eq!("U8", NumberHolderKind::U8.to_string());
+///    assert_eq!(Ok(NumberHolderKind::U8), "U8".parse());
+///    assert_eq!(&[NumberHolderKind::U8, NumberHolderKind::U16], NumberHolderKind::ALL);
+///}
+///```
+/// # Runtime Crate
+/// The generated code refers to traits and types in the `enum_unwrapper_core` crate through `::enum_unwrapper_core`, so it must be a dependency of any crate using this macro, under that name.
+/// `enum_unwrapper_core` re-exports this macro, so it can be used in place of this crate.
+/// Crates that rename the dependency, or that reach it through a re-export of another crate, can point the generated code at it with the `crate = "path"` argument instead.
+/// ```
+///# use enum_unwrapper_core::unique_try_froms;
+/// mod reexport {
+///    pub use enum_unwrapper_core as runtime;
+///}
+/// #[unique_try_froms(crate = "crate::reexport::runtime")]
+/// enum NumberHolder {
+///    U8(u8),
+///    U16(u16),
+///}
+///fn main() {
+///    assert_eq!(Ok(4), u8::try_from(NumberHolder::U8(4)).map_err(|_| ()));
+///}
+///```
+///
+/// Every path in the generated code is fully qualified through `enum_unwrapper_core`, so it does not depend on the prelude, and keeps working alongside a custom `Result` alias or in editions where [`TryFrom`] is not in the prelude.
+/// It only refers to `core`, so it can also be used in `#![no_std]` crates without `alloc`.
 ///
-/// The macro panics if attached to an [`enum`](<https://doc.rust-lang.org/1.58.1/std/keyword.enum.html>) with one or more variants containing multiple fields, such as
-/// ```no_run
-///    Variant(u8,u8),
+/// Each type that gets a [`TryFrom`] implementation, and each reference to one, also implements `enum_unwrapper_core::VariantOf` for the [`enum`](<https://doc.rust-lang.org/1.58.1/std/keyword.enum.html>), which allows writing generic code over any [`enum`](<https://doc.rust-lang.org/1.58.1/std/keyword.enum.html>) that unwraps to a type.
+/// ```
+///# use enum_unwrapper_core::unique_try_froms;
+///# #[unique_try_froms()]
+///# enum NumberHolder {
+///#    U8(u8),
+///#    U16(u16),
+///# }
+///use enum_unwrapper_core::Unwrap;
+///fn small_or_zero<E: Unwrap<u8>>(value: E) -> u8 {
+///    value.try_unwrap().unwrap_or(0)
+///}
+///assert_eq!(4, small_or_zero(NumberHolder::U8(4)));
+///```
+/// # Variant Names
+/// The names of the variants are available at runtime through `variant_name(&self)` and the `VARIANT_NAMES` constant, which lists them in the order they are declared.
+/// ```
+///# use enum_unwrapper_core::unique_try_froms;
+///# #[unique_try_froms()]
+///# enum NumberHolder {
+///#    U8(u8),
+///#    U16(u16),
+///# }
+///assert_eq!("U16", NumberHolder::U16(444).variant_name());
+///assert_eq!(&["U8", "U16"], NumberHolder::VARIANT_NAMES);
+///```
+/// # Inner Type Names
+/// Similarly, `inner_type_name(&self)` names the type held by a value's variant, as it is written in the [`enum`](<https://doc.rust-lang.org/1.58.1/std/keyword.enum.html>) definition.
+/// Variants with several fields report a tuple of their types, and variants without fields report `()`.
+/// The `INNER_TYPE_NAMES` constant pairs the name of every variant with the name of its inner type.
+/// ```
+///# use enum_unwrapper_core::unique_try_froms;
+///# #[unique_try_froms()]
+///# enum NumberHolder {
+///#    U8(u8),
+///#    U16(u16),
+///# }
+///assert_eq!("u16", NumberHolder::U16(444).inner_type_name());
+///assert_eq!(&[("U8", "u8"), ("U16", "u16")], NumberHolder::INNER_TYPE_NAMES);
+///```
+/// # Typed Accessors
+/// The [`enum`](<https://doc.rust-lang.org/1.58.1/std/keyword.enum.html>) also gets methods keyed on the type to unwrap to, which work for any type implementing `enum_unwrapper_core::VariantOf` for it
+/// - `is::<T>(&self) -> bool`
+/// - `try_as::<T>(self) -> Result<T, TryFromVariantError<Self>>`
+/// - `try_into_inner::<T>(self) -> Option<T>`, for when the error would only be discarded
+/// - `unwrap_as::<T>(self) -> T`, which panics if `self` does not hold a `T`
+/// ```
+///# use enum_unwrapper_core::unique_try_froms;
+///# #[unique_try_froms()]
+///# enum NumberHolder {
+///#    U8(u8),
+///#    U16(u16),
+///# }
+///let number = NumberHolder::U16(444);
+///assert!(number.is::<u16>());
+///assert!(!number.is::<u8>());
+///assert_eq!(None, NumberHolder::U8(4).try_into_inner::<u16>());
+///assert_eq!(444, number.unwrap_as::<u16>());
+///```
+/// # Accessors
+/// The macro also generates methods named after each variant, which work even for variants that are exempted or share their type with another variant.
+/// For a variant like `BigNumber(u64)` these are
+/// - `is_big_number(&self) -> bool`
+/// - `as_big_number(&self) -> Option<&u64>`
+/// - `as_big_number_mut(&mut self) -> Option<&mut u64>`
+/// - `into_big_number(self) -> Result<u64, Self>`
+///
+/// Variants with several fields return tuples of their fields, so `as_rectangle` for `Rectangle { width: u32, height: u32 }` returns `Option<(&u32, &u32)>`.
+/// Variants that cannot be unwrapped only get the `is_` method.
+/// Each of these methods, like every conversion, is documented with the variant it belongs to, followed by the documentation of the variant itself.
+/// ```
+///# use enum_unwrapper_core::unique_try_froms;
+///# #[unique_try_froms()]
+///# enum NumberHolder {
+///#    U8(u8),
+///#    U16(u16),
+///# }
+///let mut number = NumberHolder::U8(4);
+///assert!(number.is_u8());
+///*number.as_u8_mut().unwrap() += 1;
+///assert_eq!(Some(&5), number.as_u8());
+///assert_eq!(Some(5), number.into_u8().ok());
+///```
+/// # Conversion Errors
+/// Every conversion uses `enum_unwrapper_core::TryFromVariantError` as its [`TryFrom::Error`], and the macro generates an alias for the error of the owned conversions named after the [`enum`](<https://doc.rust-lang.org/1.58.1/std/keyword.enum.html>), such as `NumberHolderTryFromError`.
+/// It records the name of the variant the conversion expected and the one it found, and implements [`std::error::Error`] regardless of the contents of the [`enum`](<https://doc.rust-lang.org/1.58.1/std/keyword.enum.html>), as long as the default `std` feature of `enum_unwrapper_core` is enabled.
+/// ```
+///# use enum_unwrapper_core::unique_try_froms;
+///# #[unique_try_froms()]
+///# enum NumberHolder {
+///#    U8(u8),
+///#    U16(u16),
+///# }
+///let error: NumberHolderTryFromError = u8::try_from(NumberHolder::U16(444)).unwrap_err();
+///assert_eq!("U8", error.expected());
+///assert_eq!("U16", error.found());
+///assert_eq!("expected U8, found U16", error.to_string());
 ///```
-/// In such cases it is recomended to condense the data into one type, like so:
-///```no_run
-/// Variant([u8;2]),
+/// The error also hands back the [`enum`](<https://doc.rust-lang.org/1.58.1/std/keyword.enum.html>) that failed to convert, so another conversion can be tried without cloning it.
+/// ```
+///# use enum_unwrapper_core::unique_try_froms;
+///# #[unique_try_froms()]
+///# enum NumberHolder {
+///#    U8(u8),
+///#    U16(u16),
+///# }
+///let number = NumberHolder::U16(444);
+///let converted = u8::try_from(number).map(u16::from).or_else(|error| u16::try_from(error.into_inner()));
+///assert_eq!(444, converted.unwrap());
 ///```
+/// # Compile Errors
+/// Instead of panicking, the macro reports every problem it finds in the [`enum`](<https://doc.rust-lang.org/1.58.1/std/keyword.enum.html>) at once, each pointing at the offending variant or field.
 ///
-///The macro currently panics if attached to an [`enum`](<https://doc.rust-lang.org/1.58.1/std/keyword.enum.html>) definition with variants containing identical types.
+/// Attaching the macro to anything other than an [`enum`](<https://doc.rust-lang.org/1.58.1/std/keyword.enum.html>) definition is an error.
+///
+/// So are variants containing identical types, unless all but one of them are exempted or one is marked as primary, see [Duplicate Types](#duplicate-types).
+/// # Multiple and Named Fields
+/// A variant with several fields, such as `Variant(u8,u8)`, converts to a tuple of their types, in the order they are declared.
+/// Named fields are treated just like unnamed ones, so a variant with a single named field converts to the type of that field.
+/// ```
+///# use enum_unwrapper_core::unique_try_froms;
+/// #[unique_try_froms()]
+/// enum Shape {
+///    Circle { radius: f64 },
+///    Rectangle { width: u32, height: u32 },
+///    Point(i32, i32),
+///}
+///fn main() {
+///    assert_eq!(1.5,f64::try_from(Shape::Circle { radius: 1.5 }).unwrap());
+///    assert_eq!((3, 4),<(u32, u32)>::try_from(Shape::Rectangle { width: 3, height: 4 }).unwrap());
+///    assert_eq!((-1, 2),<(i32, i32)>::try_from(Shape::Point(-1, 2)).unwrap());
+///    assert_eq!((&3, &4),<(&u32, &u32)>::try_from(&Shape::Rectangle { width: 3, height: 4 }).unwrap());
+///}
+///```
+/// # Unit Variants
+/// Variants without fields, such as `Empty`, are skipped by default, only getting an `is_` [accessor](#accessors).
+/// The `unit_variants(...)` argument changes this
+/// - `unit_variants(unit)` converts them into `()`, which is only unique if there is a single such variant
+/// - `unit_variants(marker)` generates a unit struct for each of them, named after the [`enum`](<https://doc.rust-lang.org/1.58.1/std/keyword.enum.html>) and the variant, and converts them into it
+///
+/// A marker that would take the name of the [error alias](#conversion-errors) or the [kind enum](#kind-enum), as for variants named `TryFromError` or `Kind`, is reported as a compile error.
+/// ```
+///# use enum_unwrapper_core::unique_try_froms;
+/// #[unique_try_froms(unit_variants(marker))]
+/// enum Reply {
+///    Empty,
+///    Unknown,
+///    Code(u16),
+///}
+///fn main() {
+///    assert_eq!(ReplyEmpty,ReplyEmpty::try_from(Reply::Empty).unwrap());
+///    assert!(ReplyUnknown::try_from(Reply::Empty).is_err());
+///}
+///```
+/// With `unit_variants(unit)`, a single variant without fields converts into `()`.
+/// ```
+///# use enum_unwrapper_core::unique_try_froms;
+/// #[unique_try_froms(unit_variants(unit))]
+/// enum Reply {
+///    Empty,
+///    Code(u16),
+///}
+///fn main() {
+///    assert_eq!(Ok(()),<()>::try_from(Reply::Empty).map_err(|_| ()));
+///    assert!(<()>::try_from(Reply::Code(404)).is_err());
+///}
+///```
+/// # Flattening
+/// A variant holding another annotated [`enum`](<https://doc.rust-lang.org/1.58.1/std/keyword.enum.html>) can be marked with `#[unwrap(flatten(...))]`, listing types that the nested [`enum`](<https://doc.rust-lang.org/1.58.1/std/keyword.enum.html>) unwraps to.
+/// The outer [`enum`](<https://doc.rust-lang.org/1.58.1/std/keyword.enum.html>) then converts into each of those types as well, by going through the nested conversion.
+/// ```
+///# use enum_unwrapper_core::unique_try_froms;
+/// #[unique_try_froms()]
+/// enum Inner {
+///    Num(u8),
+///    Text(String),
+///}
+/// #[unique_try_froms()]
+/// enum Outer {
+///    #[unwrap(flatten(u8, String))]
+///    Nested(Inner),
+///    Code(u16),
+///}
+///fn main() {
+///    assert_eq!(4,u8::try_from(Outer::Nested(Inner::Num(4))).unwrap());
+///    let error = u8::try_from(Outer::Nested(Inner::Text(String::from("four")))).unwrap_err();
+///    assert_eq!("expected Num, found Text", error.to_string());
+///}
+///```
+/// The types have to be listed since the macro cannot see the definition of the nested [`enum`](<https://doc.rust-lang.org/1.58.1/std/keyword.enum.html>).
+/// Flattened types count towards [duplicates](#duplicate-types) like any other, and flattening a variant through the [`enum`](<https://doc.rust-lang.org/1.58.1/std/keyword.enum.html>) itself, or into the nested type, is an error.
+/// Only owned values are converted through the nested [`enum`](<https://doc.rust-lang.org/1.58.1/std/keyword.enum.html>), not references.
+/// # Smart Pointers
+/// With the `deref` argument, a variant holding a `Box<T>`, `Rc<T>` or `Arc<T>` also converts into the `T` behind the pointer, in addition to the pointer itself.
+/// A [`Box`] is moved out of for owned conversions and borrowed through for borrowed ones, while an `Rc` or `Arc` can only be borrowed through, so they only convert into `&T`.
+/// ```
+///# use enum_unwrapper_core::unique_try_froms;
+///use std::rc::Rc;
+/// #[unique_try_froms(deref)]
+/// enum Expression {
+///    Number(i64),
+///    Name(Box<String>),
+///    Shared(Rc<Vec<i64>>),
+///}
+///fn main() {
+///    assert_eq!("x",String::try_from(Expression::Name(Box::new(String::from("x")))).unwrap());
+///    let shared = Expression::Shared(Rc::new(vec![1, 2]));
+///    assert_eq!(&vec![1, 2],<&Vec<i64>>::try_from(&shared).unwrap());
+///}
+///```
+/// Types that are spelled as unsized, such as `str`, `[T]` or `dyn Trait`, cannot be moved out of a [`Box`] either, so a `Box<str>` only converts into `&str` and `&mut str`.
+/// Pointers are recognized by name, so type aliases of them are not dereferenced.
+/// Pointers to the [`enum`](<https://doc.rust-lang.org/1.58.1/std/keyword.enum.html>) itself, as in recursive [`enum`](<https://doc.rust-lang.org/1.58.1/std/keyword.enum.html>)s, are not dereferenced either, since the standard library already converts every type into itself.
+/// The types behind pointers count towards [duplicates](#duplicate-types) like any other, and can be exempted with `exclude(...)`.
+/// # Duplicate Types
+/// When several variants hold the same type, only one of them can be the target of its [`TryFrom`] implementation.
+/// Mark that variant with `#[try_from(primary)]`, the others remain reachable through their [accessors](#accessors).
+/// ```
+///# use enum_unwrapper_core::unique_try_froms;
+/// #[unique_try_froms()]
+/// enum Temperature {
+///    #[try_from(primary)]
+///    Celsius(f64),
+///    Fahrenheit(f64),
+///}
+///fn main() {
+///    assert_eq!(21.5,f64::try_from(Temperature::Celsius(21.5)).unwrap());
+///    assert!(f64::try_from(Temperature::Fahrenheit(70.7)).is_err());
+///    assert_eq!(Some(&70.7),Temperature::Fahrenheit(70.7).as_fahrenheit());
+///}
+///```
+/// # Generics
+/// Generic parameters, lifetimes and `where` clauses of the [`enum`](<https://doc.rust-lang.org/1.58.1/std/keyword.enum.html>) are carried over to every implementation.
+/// ```
+///# use enum_unwrapper_core::unique_try_froms;
+/// #[unique_try_froms(exclude(T))]
+/// enum Message<'a, T> {
+///    Payload(T),
+///    Text(&'a str),
+///    Code(u16),
+///}
+///```
+/// A variant holding a bare type parameter, or a reference or [`Box`] of one, must be exempted, since implementing [`TryFrom`] for it would break the orphan rules.
+/// Forgetting to do so is reported as a compile error on that variant.
+/// # Conditional and Deprecated Variants
+/// The `#[cfg(...)]`s of a variant are copied onto everything generated for it, so a variant that is compiled out takes its conversions and accessors with it.
+/// Its `#[deprecated]`s are copied onto its accessors, its marker struct and its variant of the kind [`enum`](<https://doc.rust-lang.org/1.58.1/std/keyword.enum.html>), so using those warns.
+/// Either kind of attribute is also picked up from a `#[cfg_attr(...)]`.
+/// ```
+///# use enum_unwrapper_core::unique_try_froms;
+/// #[unique_try_froms()]
+/// enum NumberHolder {
+///    U8(u8),
+///    #[cfg(feature = "big")]
+///    U128(u128),
+///    #[deprecated(note = "use `U8` instead")]
+///    Byte(i8),
+///}
+///```
+/// Trait implementations cannot be deprecated, so the [`TryFrom`] implementations of a deprecated variant do not warn.
+/// Variants are still compared for [duplicates](#duplicate-types) regardless of their `#[cfg(...)]`s.
+/// # Arguments
+/// Types and variants can be exempted from the generated implementations, which is useful when a [`TryFrom`] implementation would collide with one written elsewhere.
+/// ```
+///# use enum_unwrapper_core::unique_try_froms;
+/// #[unique_try_froms(exclude(String, Vec<u8>), exclude_variants(Raw))]
+/// enum Message {
+///    Text(String),
+///    Bytes(Vec<u8>),
+///    Raw(u64),
+///    Code(u16),
+///}
+///```
+/// Here only `u16` gets a [`TryFrom`] implementation.
+///
+/// - `exclude(Type, ...)` skips every variant containing one of the listed types
+/// - `exclude_variants(Variant, ...)` skips the listed variants
+/// - `unit_variants(skip | unit | marker)` decides what variants without fields convert into, see [Unit Variants](#unit-variants)
+/// - `kind` generates a [kind enum](#kind-enum) mirroring the variants
+/// - `deref` also converts variants holding a `Box`, `Rc` or `Arc` into the type behind it, see [Smart Pointers](#smart-pointers)
+/// - `option` implements [`From`] the [`enum`](<https://doc.rust-lang.org/1.58.1/std/keyword.enum.html>) for an [`Option`] of each owned type that gets a [`TryFrom`] implementation, which is [`None`] for any other variant, so a variant holding `Option<T>` has to be exempted when another one holds `T`, since [`TryFrom`] is already implemented for every [`From`] conversion
+/// - `from` also implements [`From`] for the [`enum`](<https://doc.rust-lang.org/1.58.1/std/keyword.enum.html>) from each type that gets a [`TryFrom`] implementation, so values can be wrapped as well as unwrapped
+/// - `crate = "path"` names the path `enum_unwrapper_core` is reachable through, for crates that rename it or re-export it, see [Runtime Crate](#runtime-crate)
+/// ```
+///# use enum_unwrapper_core::unique_try_froms;
+/// #[unique_try_froms(from)]
+/// enum NumberHolder {
+///    U8(u8),
+///    U16(u16),
+///}
+///fn main() {
+///    let wrapped: NumberHolder = 4u8.into();
+///    assert_eq!(4,u8::try_from(wrapped).unwrap());
+///}
+///```
+///
+/// Any other argument, or an exemption that does not match a variant of the [`enum`](<https://doc.rust-lang.org/1.58.1/std/keyword.enum.html>), is reported as a compile error.
 #[proc_macro_attribute]
-pub fn unique_try_froms (_exempt_types: TokenStream, user_enum: TokenStream) -> TokenStream {
-    let parsed_enum: &syn::ItemEnum  = &syn::parse(user_enum).expect("This attribute should only be attached to a enum definition");
+pub fn unique_try_froms (exemptions: TokenStream, user_enum: TokenStream) -> TokenStream {
+    let mut parsed_enum: syn::ItemEnum = match syn::parse(user_enum) {
+        Ok(parsed_enum) => parsed_enum,
+        Err(error) => return syn::Error::new(error.span(), "this attribute should only be attached to an enum definition").to_compile_error().into(),
+    };
+    let mut arguments = Arguments::default();
+    let mut argument_errors = None;
+    let argument_parser = syn::meta::parser(|meta| arguments.parse_or_skip(meta, &mut argument_errors));
+    if let Err(error) = syn::parse::Parser::parse(argument_parser, exemptions) {
+        argument_errors.get_or_insert(error);
+    }
+    let implementations = unwrap_enum(&parsed_enum, arguments, argument_errors);
+    parsed_enum.attrs.retain(|attribute| !attribute.path().is_ident("unwrap"));
+    for variant in &mut parsed_enum.variants {
+        variant.attrs.retain(|attribute| !attribute.path().is_ident("try_from") && !attribute.path().is_ident("unwrap"));
+    }
+    quote! {
+        #parsed_enum
+        #implementations
+    }.into()
+}
+/// # Unique TryFrom
+/// Deriving `UniqueTryFrom` on an [`enum`](<https://doc.rust-lang.org/1.58.1/std/keyword.enum.html>) generates exactly what [`macro@unique_try_froms`] does, without re-emitting the [`enum`](<https://doc.rust-lang.org/1.58.1/std/keyword.enum.html>) itself.
+/// This lets it compose with other derives and work under `cfg_attr`.
+/// # Example
+/// ```
+///# use enum_unwrapper_core::UniqueTryFrom;
+/// #[derive(Debug, Clone, UniqueTryFrom)]
+/// #[unwrap(from)]
+/// enum Message {
+///    #[unwrap(rename = "text")]
+///    Utf8(String),
+///    Code(u16),
+///    #[unwrap(skip)]
+///    Raw(u16),
+///}
+///fn main() {
+///    let message = Message::from(404u16);
+///    assert!(message.is_code());
+///    assert_eq!(Some(&String::from("hi")),Message::Utf8(String::from("hi")).as_text());
+///}
+///```
+/// # Helper Attributes
+/// - `#[unwrap(...)]` on the [`enum`](<https://doc.rust-lang.org/1.58.1/std/keyword.enum.html>) accepts the same [arguments](macro@unique_try_froms#arguments) as the attribute
+/// - `#[unwrap(skip)]` on a variant exempts it, just like `exclude_variants(...)`
+/// - `#[unwrap(rename = "name")]` on a variant replaces its name in the generated [accessors](macro@unique_try_froms#accessors), so `is_name`, `as_name` and so on are generated instead
+/// - `#[unwrap(flatten(Type, ...))]` on a variant converts through the nested [`enum`](<https://doc.rust-lang.org/1.58.1/std/keyword.enum.html>) it holds, see [Flattening](macro@unique_try_froms#flattening)
+/// - `#[try_from(primary)]` on a variant picks it when several variants hold the same type, see [Duplicate Types](macro@unique_try_froms#duplicate-types)
+///
+/// These attributes can also be used with [`macro@unique_try_froms`].
+#[proc_macro_derive(UniqueTryFrom, attributes(unwrap, try_from))]
+pub fn unique_try_from (user_enum: TokenStream) -> TokenStream {
+    let parsed_enum: syn::ItemEnum = match syn::parse(user_enum) {
+        Ok(parsed_enum) => parsed_enum,
+        Err(error) => return syn::Error::new(error.span(), "`UniqueTryFrom` can only be derived for enums").to_compile_error().into(),
+    };
+    unwrap_enum(&parsed_enum, Arguments::default(), None).into()
+}
+/// Generates every implementation for `parsed_enum`, or the errors that prevent doing so
+fn unwrap_enum(parsed_enum: &syn::ItemEnum, mut arguments: Arguments, mut errors: Option<syn::Error>) -> proc_macro2::TokenStream {
+    for attribute in parsed_enum.attrs.iter().filter(|attribute| attribute.path().is_ident("unwrap")) {
+        let mut attribute_errors = None;
+        if let Err(error) = attribute.parse_nested_meta(|meta| arguments.parse_or_skip(meta, &mut attribute_errors)) {
+            attribute_errors.get_or_insert(error);
+        }
+        if let Some(error) = attribute_errors {
+            accumulate(&mut errors, error);
+        }
+    }
+    // Exemptions may be missing after a malformed argument, so the checks relying on them would only report noise
+    let arguments_parsed = errors.is_none();
     let enum_name = &parsed_enum.ident;
+    let (_, type_generics, _) = parsed_enum.generics.split_for_impl();
+    let enum_type: syn::Type = syn::parse_quote!(#enum_name #type_generics);
     let ident_extractor = |variant: &syn::Variant| -> syn::Ident {
         variant.ident.clone()
     };
-    let inner_type_extractor = |variant: &syn::Variant| -> syn::Type {
-        match &variant.fields {
-            syn::Fields::Unnamed(wrapped) => return wrapped.unnamed.first().expect("Each enum variant should contain one inner value").ty.clone(),
-            _ => panic!("An unexpected error occoured, please only use unnamed enum variants")
+    let inner_type_extractor = |variant: &syn::Variant, options: &VariantOptions| -> UnwrappedVariant {
+        let variant_name = &variant.ident;
+        let field_types: Vec<syn::Type> = variant.fields.iter().map(|field| without_self(&field.ty, &enum_type)).collect();
+        let field_names: Vec<syn::Ident> = (0..field_types.len()).map(|index| format_ident!("field_{}", index, span = proc_macro2::Span::mixed_site())).collect();
+        let binding = match &variant.fields {
+            syn::Fields::Unnamed(_) => quote!(#enum_name::#variant_name(#(#field_names),*)),
+            syn::Fields::Named(named) => {
+                let field_idents = named.named.iter().map(|field| &field.ident);
+                quote!(#enum_name::#variant_name { #(#field_idents: #field_names),* })
+            },
+            syn::Fields::Unit => quote!(#enum_name::#variant_name),
+        };
+        let (cfgs, deprecations) = inherited_attributes(variant);
+        let marker = (field_types.is_empty() && arguments.unit_variants == UnitVariants::Marker).then(|| format_ident!("{}{}", enum_name, variant_name));
+        let (inner_value, inner_type) = match (field_types.as_slice(), &marker) {
+            ([], Some(marker)) => (quote!(#marker), syn::parse_quote!(#marker)),
+            ([field_type], _) => (quote!(#(#field_names)*), field_type.clone()),
+            _ => (quote!((#(#field_names),*)), syn::parse_quote!((#(#field_types),*))),
+        };
+        UnwrappedVariant {
+            ident: ident_extractor(variant),
+            method_name: options.rename.clone().unwrap_or_else(|| snake_case(variant_name)),
+            binding,
+            inner_value,
+            inner_type,
+            field_types,
+            marker,
+            through: None,
+            pointer: None,
+            cfgs,
+            deprecations,
+            documentation: variant_documentation(variant),
         }
     };
-    let enum_variants = parsed_enum.variants.iter().map(ident_extractor);
-    let variant_types = parsed_enum.variants.iter().map(inner_type_extractor);
-    quote! {
-        #parsed_enum
-        #(impl TryFrom<#enum_name> for #variant_types {
-            type Error = &'static str;
-            fn try_from(value: #enum_name) ->  Result<Self,Self::Error> {
+    let mut candidates: Vec<(UnwrappedVariant, bool)> = Vec::new();
+    let mut accessor_variants: Vec<UnwrappedVariant> = Vec::new();
+    let mut inner_types: Vec<syn::Type> = Vec::new();
+    let mut is_methods: Vec<syn::Ident> = Vec::new();
+    let mut inner_type_names: Vec<String> = Vec::new();
+    for variant in &parsed_enum.variants {
+        let options = match VariantOptions::parse(variant) {
+            Ok(options) => options,
+            Err(error) => {
+                accumulate(&mut errors, error);
+                VariantOptions::default()
+            },
+        };
+        let is_excluded = options.skip || arguments.excludes_variant(&variant.ident);
+        let unwrapped = inner_type_extractor(variant, &options);
+        is_methods.push(format_ident!("is_{}", unwrapped.method_name));
+        let written_types: Vec<&syn::Type> = variant.fields.iter().map(|field| &field.ty).collect();
+        inner_type_names.push(match written_types.as_slice() {
+            [field_type] => type_name(field_type),
+            field_types => format!("({})", field_types.iter().copied().map(type_name).collect::<Vec<String>>().join(", ")),
+        });
+        if variant.fields.is_empty() && arguments.unit_variants == UnitVariants::Skip {
+            continue;
+        }
+        inner_types.push(unwrapped.inner_type.clone());
+        if !is_excluded && !arguments.excludes_type(&unwrapped.inner_type) {
+            if let Some(marker) = &unwrapped.marker {
+                let colliding_item = if *marker == format_ident!("{}TryFromError", enum_name) {
+                    Some("error alias")
+                } else if arguments.kind && *marker == format_ident!("{}Kind", enum_name) {
+                    Some("kind enum")
+                } else {
+                    None
+                };
+                if let Some(item) = colliding_item {
+                    accumulate(&mut errors, syn::Error::new(variant.ident.span(), format!("the marker `{}` generated for `{}` would collide with the {} of `{}`, exempt the variant with `#[unwrap(skip)]` or rename it", marker, variant.ident, item, enum_name)));
+                }
+            }
+            candidates.push((unwrapped.clone(), options.primary));
+        }
+        if let Some(flatten) = &options.flatten {
+            match unwrapped.field_types.as_slice() {
+                [nested_type] if mentions(nested_type, enum_name) => {
+                    accumulate(&mut errors, syn::Error::new_spanned(nested_type, format!("cannot flatten `{}` through `{}`, which contains `{}` itself", variant.ident, type_name(nested_type), enum_name)));
+                },
+                [nested_type] => for (index, flattened_type) in flatten.iter().enumerate() {
+                    if mentions(flattened_type, enum_name) || same_type(flattened_type, nested_type) {
+                        accumulate(&mut errors, syn::Error::new_spanned(flattened_type, format!("cannot flatten `{}` into `{}`, since the conversion would never terminate", variant.ident, type_name(flattened_type))));
+                    } else if flatten[..index].iter().any(|previous_type| same_type(previous_type, flattened_type)) {
+                        accumulate(&mut errors, syn::Error::new_spanned(flattened_type, format!("`{}` is already flattened through `{}`", type_name(flattened_type), variant.ident)));
+                    } else {
+                        inner_types.push(flattened_type.clone());
+                        if !is_excluded && !arguments.excludes_type(flattened_type) {
+                            candidates.push((UnwrappedVariant {
+                                inner_type: flattened_type.clone(),
+                                through: Some(nested_type.clone()),
+                                ..unwrapped.clone()
+                            }, options.primary));
+                        }
+                    }
+                },
+                _ => accumulate(&mut errors, syn::Error::new(variant.ident.span(), format!("`{}` can only be flattened if it contains exactly one inner value", variant.ident))),
+            }
+        }
+        if let ([pointer_type], true) = (unwrapped.field_types.as_slice(), arguments.deref) {
+            if let Some((pointer, pointee_type)) = smart_pointer(pointer_type) {
+                if !same_type(pointee_type, &enum_type) {
+                    inner_types.push(pointee_type.clone());
+                    if !is_excluded && !arguments.excludes_type(pointee_type) {
+                        candidates.push((UnwrappedVariant {
+                            inner_type: pointee_type.clone(),
+                            pointer: Some(pointer),
+                            ..unwrapped.clone()
+                        }, options.primary));
+                    }
+                }
+            }
+        }
+        if !variant.fields.is_empty() {
+            accessor_variants.push(unwrapped);
+        }
+    }
+    if !arguments_parsed {
+        return errors.map(|errors| errors.to_compile_error()).unwrap_or_default();
+    }
+    if let Err(error) = arguments.validate(parsed_enum, &inner_types) {
+        accumulate(&mut errors, error);
+    }
+    let mut conversions: Vec<&UnwrappedVariant> = Vec::new();
+    for (unwrapped, is_primary) in &candidates {
+        let variant_type = &unwrapped.inner_type;
+        let sharing_variants: Vec<&syn::Ident> = candidates.iter()
+            .filter(|(other, _)| same_type(&other.inner_type, variant_type))
+            .map(|(other, _)| &other.ident)
+            .collect();
+        let primary_variant = candidates.iter()
+            .find(|(other, other_is_primary)| *other_is_primary && same_type(&other.inner_type, variant_type))
+            .map(|(other, _)| &other.ident);
+        match primary_variant {
+            _ if sharing_variants.len() == 1 => conversions.push(unwrapped),
+            Some(primary_variant) if *primary_variant == unwrapped.ident => conversions.push(unwrapped),
+            Some(primary_variant) if *is_primary => {
+                accumulate(&mut errors, syn::Error::new(unwrapped.ident.span(), format!("`{}` is already the primary variant for `{}`", primary_variant, quote!(#variant_type))));
+            },
+            Some(_) => {},
+            None => {
+                let variant_names: Vec<String> = sharing_variants.iter().map(|other_variant| format!("`{}`", other_variant)).collect();
+                let message = format!("`{}` is held by several variants ({}), mark one of them with `#[try_from(primary)]` or exempt the others", type_name(variant_type), variant_names.join(", "));
+                accumulate(&mut errors, match unwrapped.field_types.as_slice() {
+                    [_] => syn::Error::new_spanned(variant_type, message),
+                    _ => syn::Error::new(unwrapped.ident.span(), message),
+                });
+            },
+        }
+    }
+    for unwrapped in &conversions {
+        let variant_type = &unwrapped.inner_type;
+        if let Some(type_parameter) = uncovered_type_parameter(variant_type, &parsed_enum.generics) {
+            accumulate(&mut errors, syn::Error::new_spanned(variant_type, format!("cannot implement `TryFrom<{}>` for `{}` because the type parameter `{}` is not covered by a local type, exempt it with `exclude({})`", type_name(&enum_type), type_name(variant_type), type_parameter, type_name(variant_type))));
+        }
+        let optional_type = option_argument(variant_type).filter(|_| arguments.option && unwrapped.is_owned());
+        if let Some(wrapping) = optional_type.and_then(|optional_type| conversions.iter().find(|other| other.is_owned() && same_type(&other.inner_type, optional_type))) {
+            accumulate(&mut errors, syn::Error::new_spanned(variant_type, format!("`{}` held by `{}` collides with the conversion into `{}` that `option` generates for `{}`, exempt one of them", type_name(variant_type), unwrapped.ident, type_name(variant_type), wrapping.ident)));
+        }
+    }
+    if let Some(errors) = errors {
+        return errors.to_compile_error();
+    }
+    let (impl_generics, type_generics, where_clause) = parsed_enum.generics.split_for_impl();
+    let mixed_site = proc_macro2::Span::mixed_site();
+    let visibility = &parsed_enum.vis;
+    let crate_path = arguments.crate_path.clone().unwrap_or_else(|| syn::parse_quote!(::enum_unwrapper_core));
+    let error_name = format_ident!("{}TryFromError", enum_name);
+    let mut error_generics = parsed_enum.generics.clone();
+    error_generics.where_clause = None;
+    for parameter in &mut error_generics.params {
+        match parameter {
+            syn::GenericParam::Lifetime(lifetime) => {
+                lifetime.colon_token = None;
+                lifetime.bounds.clear();
+            },
+            syn::GenericParam::Type(type_parameter) => {
+                type_parameter.colon_token = None;
+                type_parameter.bounds.clear();
+            },
+            syn::GenericParam::Const(_) => {},
+        }
+    }
+    let error_documentation = format!("The error returned when a [`{}`] does not hold the variant an owned conversion expected", enum_name);
+    let all_variants: Vec<syn::Ident> = parsed_enum.variants.iter().map(ident_extractor).collect();
+    let variant_names: Vec<String> = all_variants.iter().map(variant_name).collect();
+    let (all_cfgs, all_deprecations): (Vec<proc_macro2::TokenStream>, Vec<proc_macro2::TokenStream>) = parsed_enum.variants.iter().map(inherited_attributes).unzip();
+    let all_documentation: Vec<proc_macro2::TokenStream> = parsed_enum.variants.iter().map(variant_documentation).collect();
+    let is_documentation = all_variants.iter().zip(&all_documentation).map(|(variant, documentation)| {
+        let summary = format!("Checks whether `self` is [`{}`](Self::{})", variant_name(variant), variant);
+        quote!(#[doc = #summary] #documentation)
+    });
+    let accessor_links: Vec<String> = accessor_variants.iter().map(|unwrapped| format!("[`{}`](Self::{})", variant_name(&unwrapped.ident), unwrapped.ident)).collect();
+    let as_documentation = accessor_variants.iter().zip(&accessor_links).map(|(unwrapped, link)| unwrapped.documented(format!("Borrows the contents of `self` if it is {}", link)));
+    let as_mut_documentation = accessor_variants.iter().zip(&accessor_links).map(|(unwrapped, link)| unwrapped.documented(format!("Mutably borrows the contents of `self` if it is {}", link)));
+    let into_documentation = accessor_variants.iter().zip(&accessor_links).map(|(unwrapped, link)| unwrapped.documented(format!("Unwraps the contents of `self` if it is {}, handing it back otherwise", link)));
+    let accessor_cfgs: Vec<&proc_macro2::TokenStream> = accessor_variants.iter().map(|unwrapped| &unwrapped.cfgs).collect();
+    let accessor_deprecations: Vec<&proc_macro2::TokenStream> = accessor_variants.iter().map(|unwrapped| &unwrapped.deprecations).collect();
+    let as_methods = accessor_variants.iter().map(|unwrapped| format_ident!("as_{}", unwrapped.method_name));
+    let as_mut_methods = accessor_variants.iter().map(|unwrapped| format_ident!("as_{}_mut", unwrapped.method_name));
+    let into_methods = accessor_variants.iter().map(|unwrapped| format_ident!("into_{}", unwrapped.method_name));
+    let accessor_bindings: Vec<&proc_macro2::TokenStream> = accessor_variants.iter().map(|unwrapped| &unwrapped.binding).collect();
+    let accessor_values: Vec<&proc_macro2::TokenStream> = accessor_variants.iter().map(|unwrapped| &unwrapped.inner_value).collect();
+    let accessor_types = accessor_variants.iter().map(|unwrapped| &unwrapped.inner_type);
+    let accessor_shared_types = accessor_variants.iter().map(|unwrapped| unwrapped.borrowed_type(quote!(&)));
+    let accessor_mutable_types = accessor_variants.iter().map(|unwrapped| unwrapped.borrowed_type(quote!(&mut)));
+    let borrow_lifetime = syn::Lifetime::new(&format!("'{}", unused_name("__unique_try_froms", &parsed_enum.generics)), mixed_site);
+    let target_parameter = format_ident!("{}", unused_name("__UniqueTryFroms", &parsed_enum.generics), span = mixed_site);
+    let mut borrowed_generics = parsed_enum.generics.clone();
+    borrowed_generics.params.insert(0, syn::GenericParam::Lifetime(syn::LifetimeParam::new(borrow_lifetime.clone())));
+    let (borrowed_impl_generics, _, _) = borrowed_generics.split_for_impl();
+    let (flattened, conversions): (Vec<&UnwrappedVariant>, Vec<&UnwrappedVariant>) = conversions.into_iter().partition(|unwrapped| unwrapped.through.is_some());
+    let flattened_names = flattened.iter().map(|unwrapped| variant_name(&unwrapped.ident));
+    let flattened_bindings = flattened.iter().map(|unwrapped| &unwrapped.binding);
+    let flattened_values = flattened.iter().map(|unwrapped| &unwrapped.inner_value);
+    let flattened_types: Vec<&syn::Type> = flattened.iter().map(|unwrapped| &unwrapped.inner_type).collect();
+    let nested_types = flattened.iter().map(|unwrapped| &unwrapped.through);
+    let flattened_cfgs = flattened.iter().map(|unwrapped| &unwrapped.cfgs);
+    let flattened_documentation = flattened.iter().map(|unwrapped| {
+        let nested_type = unwrapped.through.as_ref().map(type_name).unwrap_or_default();
+        unwrapped.documented(format!("Converts [`{}::{}`] into its contents through the nested `{}`, failing for any other variant", enum_name, variant_name(&unwrapped.ident), nested_type))
+    });
+    let (dereferenced, conversions): (Vec<&UnwrappedVariant>, Vec<&UnwrappedVariant>) = conversions.into_iter().partition(|unwrapped| unwrapped.pointer.is_some());
+    let variant_bindings: Vec<&proc_macro2::TokenStream> = conversions.iter().map(|unwrapped| &unwrapped.binding).collect();
+    let variant_values: Vec<&proc_macro2::TokenStream> = conversions.iter().map(|unwrapped| &unwrapped.inner_value).collect();
+    let variant_types: Vec<&syn::Type> = conversions.iter().map(|unwrapped| &unwrapped.inner_type).collect();
+    let variant_cfgs: Vec<&proc_macro2::TokenStream> = conversions.iter().map(|unwrapped| &unwrapped.cfgs).collect();
+    let marker_variants: Vec<&UnwrappedVariant> = conversions.iter().copied().filter(|unwrapped| unwrapped.marker.is_some()).collect();
+    let markers = marker_variants.iter().map(|unwrapped| &unwrapped.marker);
+    let marker_cfgs = marker_variants.iter().map(|unwrapped| &unwrapped.cfgs);
+    let marker_deprecations = marker_variants.iter().map(|unwrapped| &unwrapped.deprecations);
+    let marker_documentation = marker_variants.iter().map(|unwrapped| unwrapped.documented(format!("Stands in for [`{}::{}`], which holds nothing", enum_name, variant_name(&unwrapped.ident))));
+    let from_documentation = conversions.iter().map(|unwrapped| unwrapped.documented(format!("Wraps a value in [`{}::{}`]", enum_name, variant_name(&unwrapped.ident))));
+    let conversion_implementations = |impl_generics: proc_macro2::TokenStream, source_type: proc_macro2::TokenStream, conversions: Vec<(&UnwrappedVariant, proc_macro2::TokenStream, proc_macro2::TokenStream)>| {
+        let enum_variants = conversions.iter().map(|(unwrapped, _, _)| &unwrapped.ident);
+        let expected_names = conversions.iter().map(|(unwrapped, _, _)| variant_name(&unwrapped.ident));
+        let variant_bindings = conversions.iter().map(|(unwrapped, _, _)| &unwrapped.binding);
+        let target_types = conversions.iter().map(|(_, target_type, _)| target_type);
+        let target_values = conversions.iter().map(|(_, _, target_value)| target_value);
+        let cfgs = conversions.iter().map(|(unwrapped, _, _)| &unwrapped.cfgs);
+        let documentation = conversions.iter().map(|(unwrapped, _, _)| unwrapped.documented(format!("Converts [`{}::{}`] into its contents, failing for any other variant", enum_name, variant_name(&unwrapped.ident))));
+        quote_spanned! {mixed_site=>
+            #(#cfgs
+            #documentation
+            #[allow(deprecated)]
+            impl #impl_generics #crate_path::VariantOf<#source_type> for #target_types #where_clause {
+                const VARIANT: &'static str = #expected_names;
+                fn is_variant_of(value: &#source_type) -> bool {
+                    match value {
+                        #enum_name::#enum_variants { .. } => true,
+                        #[allow(unreachable_patterns)]
+                        _ => false,
+                    }
+                }
+                fn try_from_variant(value: #source_type) -> #crate_path::__private::Result<Self, #crate_path::TryFromVariantError<#source_type>> {
+                    match value {
+                        #variant_bindings => #crate_path::__private::Ok(#target_values),
+                        #[allow(unreachable_patterns)]
+                        other => #crate_path::__private::Err(#crate_path::TryFromVariantError::new(#expected_names, other.variant_name(), other)),
+                    }
+                }
+                fn from_variant(value: #source_type) -> #crate_path::__private::Option<Self> {
+                    match value {
+                        #variant_bindings => #crate_path::__private::Some(#target_values),
+                        #[allow(unreachable_patterns)]
+                        _ => #crate_path::__private::None,
+                    }
+                }
+            }
+            #cfgs
+            #documentation
+            #[allow(deprecated)]
+            impl #impl_generics #crate_path::__private::TryFrom<#source_type> for #target_types #where_clause {
+                type Error = #crate_path::TryFromVariantError<#source_type>;
+                fn try_from(value: #source_type) -> #crate_path::__private::Result<Self, Self::Error> {
+                    <Self as #crate_path::VariantOf<#source_type>>::try_from_variant(value)
+                }
+            })*
+        }
+    };
+    let boxed = dereferenced.iter().filter(|unwrapped| unwrapped.pointer == Some(SmartPointer::Box));
+    let owned_conversions: Vec<(&UnwrappedVariant, proc_macro2::TokenStream, proc_macro2::TokenStream)> = conversions.iter()
+        .map(|unwrapped| (*unwrapped, unwrapped.referenced_type(quote!()), unwrapped.inner_value.clone()))
+        .chain(boxed.clone().filter(|unwrapped| unwrapped.is_owned()).map(|unwrapped| (*unwrapped, unwrapped.referenced_type(quote!()), unwrapped.dereferenced_value(quote!()))))
+        .collect();
+    let (option_types, option_variants): (Vec<proc_macro2::TokenStream>, Vec<&UnwrappedVariant>) = owned_conversions.iter()
+        .map(|(unwrapped, target_type, _)| (target_type.clone(), *unwrapped))
+        .chain(flattened.iter().map(|unwrapped| {
+            let flattened_type = &unwrapped.inner_type;
+            (quote!(#flattened_type), *unwrapped)
+        }))
+        .unzip();
+    let option_cfgs = option_variants.iter().map(|unwrapped| &unwrapped.cfgs);
+    let option_documentation = option_variants.iter().map(|unwrapped| unwrapped.documented(format!("Converts [`{}::{}`] into [`Some`] of its contents, and any other variant into [`None`]", enum_name, variant_name(&unwrapped.ident))));
+    let owned_implementations = conversion_implementations(
+        quote!(#impl_generics),
+        quote!(#enum_name #type_generics),
+        owned_conversions,
+    );
+    let shared_implementations = conversion_implementations(
+        quote!(#borrowed_impl_generics),
+        quote!(&#borrow_lifetime #enum_name #type_generics),
+        conversions.iter().map(|unwrapped| (*unwrapped, unwrapped.borrowed_type(quote!(&#borrow_lifetime)), unwrapped.inner_value.clone()))
+            .chain(dereferenced.iter().map(|unwrapped| (*unwrapped, unwrapped.referenced_type(quote!(&#borrow_lifetime)), unwrapped.dereferenced_value(quote!(&)))))
+            .collect(),
+    );
+    let mutable_implementations = conversion_implementations(
+        quote!(#borrowed_impl_generics),
+        quote!(&#borrow_lifetime mut #enum_name #type_generics),
+        conversions.iter().map(|unwrapped| (*unwrapped, unwrapped.borrowed_type(quote!(&#borrow_lifetime mut)), unwrapped.inner_value.clone()))
+            .chain(boxed.map(|unwrapped| (*unwrapped, unwrapped.referenced_type(quote!(&#borrow_lifetime mut)), unwrapped.dereferenced_value(quote!(&mut)))))
+            .collect(),
+    );
+    let kind_implementations = arguments.kind.then(|| {
+        let kind_name = format_ident!("{}Kind", enum_name);
+        let kind_documentation = format!("The variants of [`{}`], without their contents", enum_name);
+        let kind_string = kind_name.to_string();
+        let kind_variant_documentation = all_variants.iter().zip(&all_documentation).map(|(variant, documentation)| {
+            let summary = format!("The kind of [`{}::{}`]", enum_name, variant_name(variant));
+            quote!(#[doc = #summary] #documentation)
+        });
+        quote_spanned! {mixed_site=>
+            #[doc = #kind_documentation]
+            #[derive(#crate_path::__private::Debug, #crate_path::__private::Clone, #crate_path::__private::Copy, #crate_path::__private::PartialEq, #crate_path::__private::Eq, #crate_path::__private::Hash)]
+            #visibility enum #kind_name {
+                #(#all_cfgs
+                #all_deprecations
+                #kind_variant_documentation
+                #all_variants,)*
+            }
+            #[allow(dead_code, deprecated)]
+            impl #kind_name {
+                /// Every variant, in the order they are declared
+                pub const ALL: &'static [Self] = &[#(#all_cfgs #kind_name::#all_variants,)*];
+                /// The name of the variant
+                pub fn name(&self) -> &'static str {
+                    match *self {
+                        #(#all_cfgs
+                        #kind_name::#all_variants => #variant_names,)*
+                    }
+                }
+            }
+            impl #crate_path::__private::fmt::Display for #kind_name {
+                fn fmt(&self, formatter: &mut #crate_path::__private::fmt::Formatter<'_>) -> #crate_path::__private::fmt::Result {
+                    formatter.write_str(self.name())
+                }
+            }
+            #[allow(deprecated)]
+            impl #crate_path::__private::FromStr for #kind_name {
+                type Err = #crate_path::ParseKindError;
+                fn from_str(name: &str) -> #crate_path::__private::Result<Self, Self::Err> {
+                    match name {
+                        #(#all_cfgs
+                        #variant_names => #crate_path::__private::Ok(#kind_name::#all_variants),)*
+                        _ => #crate_path::__private::Err(#crate_path::ParseKindError::new(#kind_string)),
+                    }
+                }
+            }
+            #[allow(dead_code, deprecated)]
+            impl #impl_generics #enum_name #type_generics #where_clause {
+                /// The kind of the variant `self` is
+                #visibility fn kind(&self) -> #kind_name {
+                    match *self {
+                        #(#all_cfgs
+                        #enum_name::#all_variants { .. } => #kind_name::#all_variants,)*
+                    }
+                }
+            }
+        }
+    });
+    let flattened_implementations = quote_spanned! {mixed_site=>
+        #(#flattened_cfgs
+        #flattened_documentation
+        #[allow(deprecated)]
+        impl #impl_generics #crate_path::VariantOf<#enum_name #type_generics> for #flattened_types #where_clause {
+            const VARIANT: &'static str = #flattened_names;
+            fn is_variant_of(value: &#enum_name #type_generics) -> bool {
+                match value {
+                    #flattened_bindings => <Self as #crate_path::VariantOf<#nested_types>>::is_variant_of(#flattened_values),
+                    #[allow(unreachable_patterns)]
+                    _ => false,
+                }
+            }
+            fn try_from_variant(value: #enum_name #type_generics) -> #crate_path::__private::Result<Self, #crate_path::TryFromVariantError<#enum_name #type_generics>> {
                 match value {
-                    #enum_name::#enum_variants(inner) => return Ok(inner),
-                    _ => return Err("Only variants containing an inner value of the same type as the target should be passed to this function"),
+                    #flattened_bindings => <Self as #crate_path::VariantOf<#nested_types>>::try_from_variant(#flattened_values).map_err(|error| {
+                        let (expected, found) = (error.expected(), error.found());
+                        let #flattened_values = error.into_inner();
+                        #crate_path::TryFromVariantError::new(expected, found, #flattened_bindings)
+                    }),
+                    #[allow(unreachable_patterns)]
+                    other => #crate_path::__private::Err(#crate_path::TryFromVariantError::new(#flattened_names, other.variant_name(), other)),
                 }
             }
+            fn from_variant(value: #enum_name #type_generics) -> #crate_path::__private::Option<Self> {
+                match value {
+                    #flattened_bindings => <Self as #crate_path::VariantOf<#nested_types>>::from_variant(#flattened_values),
+                    #[allow(unreachable_patterns)]
+                    _ => #crate_path::__private::None,
+                }
+            }
+        }
+        #flattened_cfgs
+        #flattened_documentation
+        #[allow(deprecated)]
+        impl #impl_generics #crate_path::__private::TryFrom<#enum_name #type_generics> for #flattened_types #where_clause {
+            type Error = #crate_path::TryFromVariantError<#enum_name #type_generics>;
+            fn try_from(value: #enum_name #type_generics) -> #crate_path::__private::Result<Self, Self::Error> {
+                <Self as #crate_path::VariantOf<#enum_name #type_generics>>::try_from_variant(value)
+            }
         })*
-    }.into()
+    };
+    let option_implementations = arguments.option.then(|| quote_spanned! {mixed_site=>
+        #(#option_cfgs
+        #option_documentation
+        #[allow(deprecated)]
+        impl #impl_generics #crate_path::__private::From<#enum_name #type_generics> for #crate_path::__private::Option<#option_types> #where_clause {
+            fn from(value: #enum_name #type_generics) -> Self {
+                <#option_types as #crate_path::VariantOf<#enum_name #type_generics>>::from_variant(value)
+            }
+        })*
+    });
+    let from_implementations = arguments.from.then(|| quote_spanned! {mixed_site=>
+        #(#variant_cfgs
+        #from_documentation
+        #[allow(deprecated)]
+        impl #impl_generics #crate_path::__private::From<#variant_types> for #enum_name #type_generics #where_clause {
+            fn from(#variant_values: #variant_types) -> Self {
+                #variant_bindings
+            }
+        })*
+    });
+    quote_spanned! {mixed_site=>
+        #[doc = #error_documentation]
+        #[allow(dead_code)]
+        #visibility type #error_name #error_generics = #crate_path::TryFromVariantError<#enum_name #type_generics>;
+        #(#marker_cfgs
+        #marker_deprecations
+        #marker_documentation
+        #[derive(#crate_path::__private::Debug, #crate_path::__private::Clone, #crate_path::__private::Copy, #crate_path::__private::PartialEq, #crate_path::__private::Eq, #crate_path::__private::Hash, #crate_path::__private::Default)]
+        #visibility struct #markers;)*
+        #[allow(dead_code, deprecated)]
+        impl #impl_generics #enum_name #type_generics #where_clause {
+            /// The names of the variants, in the order they are declared
+            #visibility const VARIANT_NAMES: &'static [&'static str] = &[#(#all_cfgs #variant_names,)*];
+            /// The name of the variant `self` is
+            #visibility fn variant_name(&self) -> &'static str {
+                match *self {
+                    #(#all_cfgs
+                    #enum_name::#all_variants { .. } => #variant_names,)*
+                }
+            }
+            /// The name of every variant, paired with the name of the type it holds
+            #visibility const INNER_TYPE_NAMES: &'static [(&'static str, &'static str)] = &[#(#all_cfgs (#variant_names, #inner_type_names),)*];
+            /// The name of the type held by the variant `self` is, as it is written in the definition
+            #visibility fn inner_type_name(&self) -> &'static str {
+                match *self {
+                    #(#all_cfgs
+                    #enum_name::#all_variants { .. } => #inner_type_names,)*
+                }
+            }
+            #(#all_cfgs
+            #all_deprecations
+            #is_documentation
+            #visibility fn #is_methods(&self) -> bool {
+                match *self {
+                    #enum_name::#all_variants { .. } => true,
+                    #[allow(unreachable_patterns)]
+                    _ => false,
+                }
+            })*
+            #(#accessor_cfgs
+            #accessor_deprecations
+            #as_documentation
+            #visibility fn #as_methods(&self) -> #crate_path::__private::Option<#accessor_shared_types> {
+                match self {
+                    #accessor_bindings => #crate_path::__private::Some(#accessor_values),
+                    #[allow(unreachable_patterns)]
+                    _ => #crate_path::__private::None,
+                }
+            })*
+            #(#accessor_cfgs
+            #accessor_deprecations
+            #as_mut_documentation
+            #visibility fn #as_mut_methods(&mut self) -> #crate_path::__private::Option<#accessor_mutable_types> {
+                match self {
+                    #accessor_bindings => #crate_path::__private::Some(#accessor_values),
+                    #[allow(unreachable_patterns)]
+                    _ => #crate_path::__private::None,
+                }
+            })*
+            #(#accessor_cfgs
+            #accessor_deprecations
+            #into_documentation
+            #visibility fn #into_methods(self) -> #crate_path::__private::Result<#accessor_types, Self> {
+                match self {
+                    #accessor_bindings => #crate_path::__private::Ok(#accessor_values),
+                    #[allow(unreachable_patterns)]
+                    other => #crate_path::__private::Err(other),
+                }
+            })*
+            /// Checks whether `self` is the variant that unwraps to the given type
+            #visibility fn is<#target_parameter: #crate_path::VariantOf<Self>>(&self) -> bool {
+                #target_parameter::is_variant_of(self)
+            }
+            /// Unwraps `self` into the given type, handing it back inside the error if it is another variant
+            #visibility fn try_as<#target_parameter: #crate_path::VariantOf<Self>>(self) -> #crate_path::__private::Result<#target_parameter, #crate_path::TryFromVariantError<Self>> {
+                #target_parameter::try_from_variant(self)
+            }
+            /// Unwraps `self` into the given type, discarding it if it is another variant
+            #visibility fn try_into_inner<#target_parameter: #crate_path::VariantOf<Self>>(self) -> #crate_path::__private::Option<#target_parameter> {
+                #target_parameter::from_variant(self)
+            }
+            /// Unwraps `self` into the given type
+            ///
+            /// # Panics
+            /// Panics if `self` is another variant.
+            #[track_caller]
+            #visibility fn unwrap_as<#target_parameter: #crate_path::VariantOf<Self>>(self) -> #target_parameter {
+                match #target_parameter::try_from_variant(self) {
+                    #crate_path::__private::Ok(inner) => inner,
+                    #crate_path::__private::Err(error) => #crate_path::__private::unwrap_as_failed(error),
+                }
+            }
+        }
+        #owned_implementations
+        #shared_implementations
+        #mutable_implementations
+        #flattened_implementations
+        #option_implementations
+        #from_implementations
+        #kind_implementations
+    }
+}
+/// The arguments accepted by [`macro@unique_try_froms`]
+#[derive(Default)]
+struct Arguments {
+    excluded_types: Vec<syn::Type>,
+    excluded_variants: Vec<syn::Ident>,
+    from: bool,
+    unit_variants: UnitVariants,
+    kind: bool,
+    deref: bool,
+    option: bool,
+    crate_path: Option<syn::Path>,
+}
+/// What [`macro@unique_try_froms`] converts variants without fields into
+#[derive(Default, PartialEq)]
+enum UnitVariants {
+    /// Nothing, the variants are skipped
+    #[default]
+    Skip,
+    /// The unit type `()`
+    Unit,
+    /// A generated unit struct named after the variant
+    Marker,
+}
+impl Arguments {
+    /// Parses one argument, recording its error and skipping to the next one so that every malformed argument gets reported
+    ///
+    /// The arguments skipped this way leave tokens behind, so the error from the surrounding parser is only worth reporting if nothing was recorded here.
+    fn parse_or_skip(&mut self, meta: syn::meta::ParseNestedMeta, errors: &mut Option<syn::Error>) -> syn::Result<()> {
+        let input = meta.input;
+        if let Err(error) = self.parse(meta) {
+            accumulate(errors, error);
+            input.step(|cursor| {
+                let mut rest = *cursor;
+                while let Some((token, next)) = rest.token_tree() {
+                    match token {
+                        proc_macro2::TokenTree::Punct(punct) if punct.as_char() == ',' => break,
+                        _ => rest = next,
+                    }
+                }
+                Ok(((), rest))
+            })?;
+        }
+        Ok(())
+    }
+    fn parse(&mut self, meta: syn::meta::ParseNestedMeta) -> syn::Result<()> {
+        if meta.path.is_ident("exclude") {
+            let content;
+            syn::parenthesized!(content in meta.input);
+            self.excluded_types.extend(content.parse_terminated(syn::Type::parse, syn::Token![,])?);
+            Ok(())
+        } else if meta.path.is_ident("exclude_variants") {
+            let content;
+            syn::parenthesized!(content in meta.input);
+            self.excluded_variants.extend(content.parse_terminated(syn::Ident::parse, syn::Token![,])?);
+            Ok(())
+        } else if meta.path.is_ident("from") {
+            self.from = true;
+            flag(&meta)
+        } else if meta.path.is_ident("unit_variants") {
+            meta.parse_nested_meta(|mode| {
+                self.unit_variants = if mode.path.is_ident("skip") {
+                    UnitVariants::Skip
+                } else if mode.path.is_ident("unit") {
+                    UnitVariants::Unit
+                } else if mode.path.is_ident("marker") {
+                    UnitVariants::Marker
+                } else {
+                    return Err(mode.error("unsupported unit variant mode, expected `skip`, `unit` or `marker`"));
+                };
+                Ok(())
+            })
+        } else if meta.path.is_ident("kind") {
+            self.kind = true;
+            flag(&meta)
+        } else if meta.path.is_ident("deref") {
+            self.deref = true;
+            flag(&meta)
+        } else if meta.path.is_ident("option") {
+            self.option = true;
+            flag(&meta)
+        } else if meta.path.is_ident("crate") {
+            self.crate_path = Some(meta.value()?.parse::<syn::LitStr>()?.parse()?);
+            Ok(())
+        } else {
+            Err(meta.error("unsupported argument, expected `exclude(...)`, `exclude_variants(...)`, `from`, `unit_variants(...)`, `kind`, `deref`, `option` or `crate = \"...\"`"))
+        }
+    }
+    /// Checks that every exemption refers to something that actually appears in the [`enum`](<https://doc.rust-lang.org/1.58.1/std/keyword.enum.html>)
+    fn validate(&self, parsed_enum: &syn::ItemEnum, inner_types: &[syn::Type]) -> syn::Result<()> {
+        let mut errors = None;
+        for excluded_variant in &self.excluded_variants {
+            if !parsed_enum.variants.iter().any(|variant| &variant.ident == excluded_variant) {
+                accumulate(&mut errors, syn::Error::new(excluded_variant.span(), format!("`{}` has no variant named `{}`", parsed_enum.ident, excluded_variant)));
+            }
+        }
+        for excluded_type in &self.excluded_types {
+            if !inner_types.iter().any(|inner_type| same_type(inner_type, excluded_type)) {
+                accumulate(&mut errors, syn::Error::new_spanned(excluded_type, format!("no variant of `{}` contains `{}`", parsed_enum.ident, quote!(#excluded_type))));
+            }
+        }
+        errors.map_or(Ok(()), Err)
+    }
+    fn excludes_variant(&self, variant: &syn::Ident) -> bool {
+        self.excluded_variants.contains(variant)
+    }
+    fn excludes_type(&self, variant_type: &syn::Type) -> bool {
+        self.excluded_types.iter().any(|excluded_type| same_type(excluded_type, variant_type))
+    }
+}
+/// Finds a type parameter of `generics` that `variant_type` leaves uncovered, such as `T` in `T`, `&T` or `Box<T>`
+///
+/// Implementing [`TryFrom`] for such a type would violate the orphan rules, since the type parameter appears before the first local type.
+fn uncovered_type_parameter<'a>(variant_type: &syn::Type, generics: &'a syn::Generics) -> Option<&'a syn::Ident> {
+    match variant_type {
+        syn::Type::Path(type_path) if type_path.qself.is_none() => {
+            if let Some(ident) = type_path.path.get_ident() {
+                return generics.type_params().map(|type_parameter| &type_parameter.ident).find(|type_parameter| *type_parameter == ident);
+            }
+            let last_segment = type_path.path.segments.last()?;
+            match &last_segment.arguments {
+                syn::PathArguments::AngleBracketed(arguments) if last_segment.ident == "Box" || last_segment.ident == "Pin" => match arguments.args.first() {
+                    Some(syn::GenericArgument::Type(inner_type)) => uncovered_type_parameter(inner_type, generics),
+                    _ => None,
+                },
+                _ => None,
+            }
+        },
+        syn::Type::Reference(reference) => uncovered_type_parameter(&reference.elem, generics),
+        syn::Type::Paren(parenthesized) => uncovered_type_parameter(&parenthesized.elem, generics),
+        syn::Type::Group(group) => uncovered_type_parameter(&group.elem, generics),
+        _ => None,
+    }
+}
+/// Picks out the attributes of `variant` that the items generated for it should carry, returning its `#[cfg]`s and its `#[deprecated]`s
+///
+/// A `#[cfg_attr(...)]` is kept as long as it applies one of those, and only with those.
+fn inherited_attributes(variant: &syn::Variant) -> (proc_macro2::TokenStream, proc_macro2::TokenStream) {
+    let mut cfgs = proc_macro2::TokenStream::new();
+    let mut deprecations = proc_macro2::TokenStream::new();
+    for attribute in &variant.attrs {
+        if attribute.path().is_ident("cfg") {
+            cfgs.extend(quote!(#attribute));
+        } else if attribute.path().is_ident("deprecated") {
+            deprecations.extend(quote!(#attribute));
+        } else if attribute.path().is_ident("cfg_attr") {
+            let parsed = attribute.parse_args_with(|input: syn::parse::ParseStream| {
+                let predicate: syn::Meta = input.parse()?;
+                input.parse::<syn::Token![,]>()?;
+                Ok((predicate, input.parse_terminated(syn::Meta::parse, syn::Token![,])?))
+            });
+            if let Ok((predicate, applied)) = parsed {
+                let (applied_cfgs, applied_deprecations): (Vec<syn::Meta>, Vec<syn::Meta>) = applied.into_iter()
+                    .filter(|meta| meta.path().is_ident("cfg") || meta.path().is_ident("deprecated"))
+                    .partition(|meta| meta.path().is_ident("cfg"));
+                if !applied_cfgs.is_empty() {
+                    cfgs.extend(quote!(#[cfg_attr(#predicate, #(#applied_cfgs),*)]));
+                }
+                if !applied_deprecations.is_empty() {
+                    deprecations.extend(quote!(#[cfg_attr(#predicate, #(#applied_deprecations),*)]));
+                }
+            }
+        }
+    }
+    (cfgs, deprecations)
+}
+/// The `///` documentation of `variant`, set apart by an empty line so it can follow the summary of a generated item
+fn variant_documentation(variant: &syn::Variant) -> proc_macro2::TokenStream {
+    let documentation: Vec<&syn::Attribute> = variant.attrs.iter().filter(|attribute| attribute.path().is_ident("doc")).collect();
+    if documentation.is_empty() {
+        proc_macro2::TokenStream::new()
+    } else {
+        quote!(#[doc = ""] #(#documentation)*)
+    }
+}
+/// Recognizes `Box<T>`, `Rc<T>` and `Arc<T>` by name, returning the kind of pointer along with `T`
+fn smart_pointer(pointer_type: &syn::Type) -> Option<(SmartPointer, &syn::Type)> {
+    let last_segment = match pointer_type {
+        syn::Type::Path(type_path) if type_path.qself.is_none() => type_path.path.segments.last()?,
+        _ => return None,
+    };
+    let pointer = if last_segment.ident == "Box" {
+        SmartPointer::Box
+    } else if last_segment.ident == "Rc" || last_segment.ident == "Arc" {
+        SmartPointer::Shared
+    } else {
+        return None;
+    };
+    match &last_segment.arguments {
+        syn::PathArguments::AngleBracketed(arguments) if arguments.args.len() == 1 => match arguments.args.first() {
+            Some(syn::GenericArgument::Type(pointee_type)) => Some((pointer, pointee_type)),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+/// Checks whether `inner_type` is spelled as a dynamically sized type, such as `str`, `[T]` or `dyn Trait`, which cannot be moved out of a [`Box`]
+fn is_unsized(inner_type: &syn::Type) -> bool {
+    match inner_type {
+        syn::Type::Path(type_path) => type_path.qself.is_none() && type_path.path.is_ident("str"),
+        syn::Type::Slice(_) | syn::Type::TraitObject(_) => true,
+        syn::Type::Paren(parenthesized) => is_unsized(&parenthesized.elem),
+        syn::Type::Group(group) => is_unsized(&group.elem),
+        _ => false,
+    }
+}
+/// `name`, followed by as many underscores as it takes not to collide with a generic parameter of `generics`
+fn unused_name(name: &str, generics: &syn::Generics) -> String {
+    let mut name = name.to_string();
+    while generics.params.iter().any(|parameter| match parameter {
+        syn::GenericParam::Lifetime(lifetime) => lifetime.lifetime.ident == name,
+        syn::GenericParam::Type(type_parameter) => type_parameter.ident == name,
+        syn::GenericParam::Const(const_parameter) => const_parameter.ident == name,
+    }) {
+        name.push('_');
+    }
+    name
+}
+/// Checks that an argument which only acts as a flag was not given a value
+fn flag(meta: &syn::meta::ParseNestedMeta) -> syn::Result<()> {
+    let path = &meta.path;
+    if meta.input.is_empty() || meta.input.peek(syn::Token![,]) {
+        Ok(())
+    } else {
+        Err(meta.error(format!("`{}` does not take a value", quote!(#path))))
+    }
+}
+/// Adds `error` to the errors found so far, so they can all be reported together
+fn accumulate(errors: &mut Option<syn::Error>, error: syn::Error) {
+    match errors {
+        Some(errors) => errors.combine(error),
+        None => *errors = Some(error),
+    }
+}
+/// A variant whose fields are unwrapped into a single inner value
+#[derive(Clone)]
+struct UnwrappedVariant {
+    ident: syn::Ident,
+    /// The name used in the generated accessor methods, such as `big_number` in `as_big_number`
+    method_name: String,
+    /// The variant with its fields bound to generated names, usable both as a pattern and as an expression
+    binding: proc_macro2::TokenStream,
+    /// The generated names of the fields, either alone or as a tuple, usable both as a pattern and as an expression
+    inner_value: proc_macro2::TokenStream,
+    /// The type of `inner_value`, which is a tuple for variants with multiple fields
+    inner_type: syn::Type,
+    field_types: Vec<syn::Type>,
+    /// The unit struct generated for a variant without fields, when `unit_variants(marker)` is used
+    marker: Option<syn::Ident>,
+    /// The type of the nested [`enum`](<https://doc.rust-lang.org/1.58.1/std/keyword.enum.html>) that `inner_type` is unwrapped through, when the variant is flattened
+    through: Option<syn::Type>,
+    /// The smart pointer that `inner_type` is dereferenced out of, when the `deref` argument is used
+    pointer: Option<SmartPointer>,
+    /// The `#[cfg]`s of the variant, which every item generated for it carries
+    cfgs: proc_macro2::TokenStream,
+    /// The `#[deprecated]`s of the variant, which the generated items that can be deprecated carry
+    deprecations: proc_macro2::TokenStream,
+    /// The documentation of the variant, which is appended to that of every item generated for it
+    documentation: proc_macro2::TokenStream,
+}
+/// A smart pointer recognized by the `deref` argument
+#[derive(Clone, Copy, PartialEq)]
+enum SmartPointer {
+    /// A [`Box`], which can be moved out of as well as borrowed through
+    Box,
+    /// An `Rc` or `Arc`, which can only be borrowed through
+    Shared,
+}
+impl UnwrappedVariant {
+    /// Whether the variant gets an owned conversion into `inner_type`, rather than only borrowed ones through a smart pointer
+    fn is_owned(&self) -> bool {
+        match self.pointer {
+            None => true,
+            Some(SmartPointer::Box) => !is_unsized(&self.inner_type),
+            Some(SmartPointer::Shared) => false,
+        }
+    }
+    /// The type of `inner_value` when the variant is matched through `reference`, such as `&u8` or `(&u8, &u16)`
+    ///
+    /// Nothing is borrowed from variants without fields, so their inner type is used as is.
+    fn borrowed_type(&self, reference: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        match self.field_types.as_slice() {
+            [] => {
+                let inner_type = &self.inner_type;
+                quote!(#inner_type)
+            },
+            [field_type] => quote!(#reference #field_type),
+            field_types => quote!((#(#reference #field_types),*)),
+        }
+    }
+    /// Documents an item generated for the variant with `summary`, followed by the documentation of the variant itself
+    fn documented(&self, summary: String) -> proc_macro2::TokenStream {
+        let documentation = &self.documentation;
+        quote!(#[doc = #summary] #documentation)
+    }
+    /// `inner_type` behind `reference`, such as `Node` or `&Node`, without borrowing each field separately
+    fn referenced_type(&self, reference: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        let inner_type = &self.inner_type;
+        quote!(#reference #inner_type)
+    }
+    /// The value held by a smart pointer variant, moved out of the [`Box`] when `reference` is empty and borrowed through it otherwise
+    fn dereferenced_value(&self, reference: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        let inner_value = &self.inner_value;
+        if reference.is_empty() {
+            quote!(*#inner_value)
+        } else {
+            quote!(#reference **#inner_value)
+        }
+    }
+}
+/// The options attached to a single variant through `#[try_from(...)]` and `#[unwrap(...)]`
+#[derive(Default)]
+struct VariantOptions {
+    primary: bool,
+    skip: bool,
+    rename: Option<String>,
+    flatten: Option<Vec<syn::Type>>,
+}
+impl VariantOptions {
+    fn parse(variant: &syn::Variant) -> syn::Result<Self> {
+        let mut options = VariantOptions::default();
+        let mut errors = None;
+        for attribute in &variant.attrs {
+            let parsed = if attribute.path().is_ident("try_from") {
+                attribute.parse_nested_meta(|meta| {
+                    if meta.path.is_ident("primary") {
+                        options.primary = true;
+                        Ok(())
+                    } else {
+                        Err(meta.error("unsupported variant option, expected `primary`"))
+                    }
+                })
+            } else if attribute.path().is_ident("unwrap") {
+                attribute.parse_nested_meta(|meta| {
+                    if meta.path.is_ident("skip") {
+                        options.skip = true;
+                        Ok(())
+                    } else if meta.path.is_ident("rename") {
+                        let method_name: syn::Ident = meta.value()?.parse::<syn::LitStr>()?.parse()?;
+                        options.rename = Some(method_name.to_string());
+                        Ok(())
+                    } else if meta.path.is_ident("flatten") {
+                        if meta.input.is_empty() || meta.input.peek(syn::Token![,]) {
+                            return Err(meta.error("list the types to unwrap through the nested enum, such as `flatten(u8, String)`"));
+                        }
+                        let content;
+                        syn::parenthesized!(content in meta.input);
+                        options.flatten.get_or_insert_with(Vec::new).extend(content.parse_terminated(syn::Type::parse, syn::Token![,])?);
+                        Ok(())
+                    } else {
+                        Err(meta.error("unsupported variant option, expected `skip`, `rename = \"...\"` or `flatten(...)`"))
+                    }
+                })
+            } else {
+                continue;
+            };
+            if let Err(error) = parsed {
+                accumulate(&mut errors, error);
+            }
+        }
+        errors.map_or(Ok(options), Err)
+    }
+}
+/// The name of `variant` as it is reported at runtime, without any `r#` prefix
+fn variant_name(variant: &syn::Ident) -> String {
+    variant.to_string().trim_start_matches("r#").to_string()
+}
+/// Converts a variant name such as `BigNumber` into the `big_number` used in generated method names
+fn snake_case(variant: &syn::Ident) -> String {
+    let characters: Vec<char> = variant_name(variant).chars().collect();
+    let mut snake_case = String::new();
+    for (index, character) in characters.iter().enumerate() {
+        if character.is_uppercase() && index > 0 {
+            let previous = characters[index - 1];
+            let next_is_lowercase = characters.get(index + 1).is_some_and(|next| next.is_lowercase());
+            if previous.is_lowercase() || previous.is_ascii_digit() || (previous.is_uppercase() && next_is_lowercase) {
+                snake_case.push('_');
+            }
+        }
+        snake_case.extend(character.to_lowercase());
+    }
+    snake_case
+}
+/// Writes `inner_type` the way it appears in source code, such as `Vec<u8>` rather than `Vec < u8 >`
+fn type_name(inner_type: &syn::Type) -> String {
+    let mut type_name = quote!(#inner_type).to_string();
+    for (spaced, compact) in [(" :: ", "::"), (":: ", "::"), (" <", "<"), ("< ", "<"), (" >", ">"), (" ,", ","), (" ;", ";"), ("& ", "&"), ("( ", "("), (" )", ")"), ("[ ", "["), (" ]", "]")] {
+        type_name = type_name.replace(spaced, compact);
+    }
+    type_name
+}
+/// Checks whether `inner_type` refers to the [`enum`](<https://doc.rust-lang.org/1.58.1/std/keyword.enum.html>) named `enum_name`, either by name or as `Self`
+fn mentions(inner_type: &syn::Type, enum_name: &syn::Ident) -> bool {
+    fn search(tokens: proc_macro2::TokenStream, enum_name: &syn::Ident) -> bool {
+        tokens.into_iter().any(|token| match token {
+            proc_macro2::TokenTree::Ident(ident) => ident == *enum_name || ident == "Self",
+            proc_macro2::TokenTree::Group(group) => search(group.stream(), enum_name),
+            _ => false,
+        })
+    }
+    search(quote!(#inner_type), enum_name)
+}
+/// The `T` in `inner_type`, if it is spelled as an `Option<T>`
+fn option_argument(inner_type: &syn::Type) -> Option<&syn::Type> {
+    let last_segment = match inner_type {
+        syn::Type::Path(type_path) if type_path.qself.is_none() => type_path.path.segments.last()?,
+        _ => return None,
+    };
+    match &last_segment.arguments {
+        syn::PathArguments::AngleBracketed(arguments) if last_segment.ident == "Option" && arguments.args.len() == 1 => match arguments.args.first() {
+            Some(syn::GenericArgument::Type(optional_type)) => Some(optional_type),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+/// `inner_type` with every `Self` replaced by `enum_type`, since `Self` cannot be used in the header of the generated implementations
+fn without_self(inner_type: &syn::Type, enum_type: &syn::Type) -> syn::Type {
+    fn replace(tokens: proc_macro2::TokenStream, enum_type: &syn::Type) -> proc_macro2::TokenStream {
+        let mut tokens = tokens.into_iter().peekable();
+        let mut replaced = proc_macro2::TokenStream::new();
+        while let Some(token) = tokens.next() {
+            match token {
+                proc_macro2::TokenTree::Ident(ident) if ident == "Self" => match tokens.peek() {
+                    Some(proc_macro2::TokenTree::Punct(punct)) if punct.as_char() == ':' => replaced.extend(quote!(<#enum_type>)),
+                    _ => replaced.extend(quote!(#enum_type)),
+                },
+                proc_macro2::TokenTree::Group(group) => {
+                    let mut replaced_group = proc_macro2::Group::new(group.delimiter(), replace(group.stream(), enum_type));
+                    replaced_group.set_span(group.span());
+                    replaced.extend([proc_macro2::TokenTree::Group(replaced_group)]);
+                },
+                token => replaced.extend([token]),
+            }
+        }
+        replaced
+    }
+    syn::parse2(replace(quote!(#inner_type), enum_type)).unwrap_or_else(|_| inner_type.clone())
+}
+/// Compares two types by their tokens, ignoring spans
+fn same_type(first: &syn::Type, second: &syn::Type) -> bool {
+    quote!(#first).to_string() == quote!(#second).to_string()
 }